@@ -0,0 +1,3 @@
+DROP INDEX quran_words_normalized_word_trgm;
+ALTER TABLE quran_words DROP COLUMN normalized_word;
+DROP FUNCTION quran_normalize_arabic(TEXT);
@@ -0,0 +1,17 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Removes the harakat, quranic marks and tatweel and unifies the
+-- alef, hamza, ya and kaf variants, so a word can be found without
+-- typing the exact script of the mushaf
+CREATE OR REPLACE FUNCTION quran_normalize_arabic(input TEXT) RETURNS TEXT AS $$
+    SELECT translate(
+        regexp_replace(input, '[\u0610-\u061A\u0640\u064B-\u065F\u0670\u06D6-\u06ED\u08D3-\u08FF]', '', 'g'),
+        'أإآٱؤئىیک',
+        'ااااويييك'
+    );
+$$ LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE;
+
+ALTER TABLE quran_words
+    ADD COLUMN normalized_word TEXT NOT NULL GENERATED ALWAYS AS (quran_normalize_arabic(word)) STORED;
+
+CREATE INDEX quran_words_normalized_word_trgm ON quran_words USING gin (normalized_word gin_trgm_ops);
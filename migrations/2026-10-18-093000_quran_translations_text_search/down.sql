@@ -0,0 +1,7 @@
+DROP TRIGGER quran_translations_language ON quran_translations;
+DROP FUNCTION quran_translations_language_update();
+DROP TRIGGER quran_translations_text_search ON quran_translations_text;
+DROP FUNCTION quran_translations_text_search_update();
+DROP INDEX quran_translations_text_search_idx;
+ALTER TABLE quran_translations_text DROP COLUMN text_search;
+DROP FUNCTION translation_search_config(TEXT);
@@ -0,0 +1,72 @@
+-- Text search configuration of a translation language,
+-- languages without a stemmer (like fa and ur) use the simple configuration
+CREATE OR REPLACE FUNCTION translation_search_config(language TEXT) RETURNS regconfig AS $$
+    SELECT (CASE lower(split_part(language, '-', 1))
+        WHEN 'ar' THEN 'arabic'
+        WHEN 'da' THEN 'danish'
+        WHEN 'de' THEN 'german'
+        WHEN 'el' THEN 'greek'
+        WHEN 'en' THEN 'english'
+        WHEN 'es' THEN 'spanish'
+        WHEN 'fi' THEN 'finnish'
+        WHEN 'fr' THEN 'french'
+        WHEN 'ga' THEN 'irish'
+        WHEN 'hu' THEN 'hungarian'
+        WHEN 'id' THEN 'indonesian'
+        WHEN 'it' THEN 'italian'
+        WHEN 'lt' THEN 'lithuanian'
+        WHEN 'ne' THEN 'nepali'
+        WHEN 'nl' THEN 'dutch'
+        WHEN 'no' THEN 'norwegian'
+        WHEN 'pt' THEN 'portuguese'
+        WHEN 'ro' THEN 'romanian'
+        WHEN 'ru' THEN 'russian'
+        WHEN 'sv' THEN 'swedish'
+        WHEN 'ta' THEN 'tamil'
+        WHEN 'tr' THEN 'turkish'
+        ELSE 'simple'
+    END)::regconfig;
+$$ LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE;
+
+ALTER TABLE quran_translations_text ADD COLUMN text_search tsvector;
+
+UPDATE quran_translations_text
+    SET text_search = to_tsvector(translation_search_config(quran_translations.language), quran_translations_text.text)
+    FROM quran_translations
+    WHERE quran_translations.id = quran_translations_text.translation_id;
+
+ALTER TABLE quran_translations_text ALTER COLUMN text_search SET NOT NULL;
+
+CREATE INDEX quran_translations_text_search_idx ON quran_translations_text USING gin (text_search);
+
+-- The language is on the translation, so the vector is kept by triggers
+-- instead of a generated column
+CREATE OR REPLACE FUNCTION quran_translations_text_search_update() RETURNS trigger AS $$
+BEGIN
+    NEW.text_search := to_tsvector(
+        translation_search_config((SELECT language FROM quran_translations WHERE id = NEW.translation_id)),
+        NEW.text
+    );
+
+    RETURN NEW;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER quran_translations_text_search
+    BEFORE INSERT OR UPDATE OF text, translation_id ON quran_translations_text
+    FOR EACH ROW EXECUTE FUNCTION quran_translations_text_search_update();
+
+CREATE OR REPLACE FUNCTION quran_translations_language_update() RETURNS trigger AS $$
+BEGIN
+    UPDATE quran_translations_text
+        SET text_search = to_tsvector(translation_search_config(NEW.language), text)
+        WHERE translation_id = NEW.id;
+
+    RETURN NEW;
+END
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER quran_translations_language
+    AFTER UPDATE OF language ON quran_translations
+    FOR EACH ROW WHEN (OLD.language IS DISTINCT FROM NEW.language)
+    EXECUTE FUNCTION quran_translations_language_update();
@@ -0,0 +1,22 @@
+CREATE TABLE quran_divisions (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    mushaf_id serial NOT NULL,
+    division_type VARCHAR(10) NOT NULL,
+    number serial NOT NULL,
+    start_ayah_id serial NOT NULL,
+    end_ayah_id serial NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_divisions_id PRIMARY KEY (id),
+    CONSTRAINT quran_divisions_type_check CHECK (division_type IN ('juz', 'hizb', 'rub', 'manzil')),
+    CONSTRAINT quran_divisions_unique_number UNIQUE (mushaf_id, division_type, number),
+    CONSTRAINT division_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT division_fk_mushaf_id FOREIGN KEY(mushaf_id) REFERENCES quran_mushafs(id)
+        on delete cascade,
+    CONSTRAINT division_fk_start_ayah_id FOREIGN KEY(start_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade,
+    CONSTRAINT division_fk_end_ayah_id FOREIGN KEY(end_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade
+);
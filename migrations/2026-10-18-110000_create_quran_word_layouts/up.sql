@@ -0,0 +1,16 @@
+CREATE TABLE quran_word_layouts (
+    id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    word_id serial NOT NULL,
+    page serial NOT NULL,
+    line serial NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_layouts_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_layouts_word UNIQUE (word_id),
+    CONSTRAINT word_layout_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_layout_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
+
+CREATE INDEX quran_word_layouts_page ON quran_word_layouts (page, line);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE quran_word_segments;
+DROP TABLE quran_word_morphologies;
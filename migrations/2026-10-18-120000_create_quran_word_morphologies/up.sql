@@ -0,0 +1,41 @@
+CREATE TABLE quran_word_morphologies (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    word_id serial NOT NULL,
+    root TEXT,
+    lemma TEXT,
+    part_of_speech VARCHAR(10) NOT NULL,
+    person VARCHAR(1),
+    gender VARCHAR(1),
+    grammatical_number VARCHAR(1),
+    grammatical_case VARCHAR(3),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_morphologies_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_morphologies_word UNIQUE (word_id),
+    CONSTRAINT word_morphology_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_morphology_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
+
+CREATE INDEX quran_word_morphologies_root ON quran_word_morphologies (root);
+CREATE INDEX quran_word_morphologies_lemma ON quran_word_morphologies (lemma);
+
+-- Prefix, stem and suffix parts of the word, in order
+CREATE TABLE quran_word_segments (
+    id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    morphology_id serial NOT NULL,
+    segment_number serial NOT NULL,
+    form TEXT NOT NULL,
+    tag VARCHAR(10) NOT NULL,
+    features TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_segments_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_segments_number UNIQUE (morphology_id, segment_number),
+    CONSTRAINT word_segment_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_segment_fk_morphology_id FOREIGN KEY(morphology_id) REFERENCES quran_word_morphologies(id)
+        on delete cascade
+);
@@ -0,0 +1,16 @@
+CREATE TABLE quran_word_translations (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    word_id serial NOT NULL,
+    language VARCHAR(5) NOT NULL,
+    text TEXT NOT NULL,
+    transliteration TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_translations_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_translations_language UNIQUE (word_id, language),
+    CONSTRAINT word_translation_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_translation_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE quran_recitation_words;
+DROP TABLE quran_recitation_ayahs;
+DROP TABLE quran_recitations;
+DROP TABLE quran_reciters;
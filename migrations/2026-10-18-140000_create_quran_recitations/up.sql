@@ -0,0 +1,70 @@
+CREATE TABLE quran_reciters (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    account_id INT,
+    name VARCHAR(300) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_reciters_id PRIMARY KEY (id),
+    CONSTRAINT reciter_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT reciter_fk_account_id FOREIGN KEY(account_id) REFERENCES app_accounts(id)
+        on delete set null
+);
+
+CREATE TABLE quran_recitations (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    reciter_id serial NOT NULL,
+    mushaf_id serial NOT NULL,
+    style VARCHAR(30),
+    source VARCHAR(300),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_recitations_id PRIMARY KEY (id),
+    CONSTRAINT recitation_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT recitation_fk_reciter_id FOREIGN KEY(reciter_id) REFERENCES quran_reciters(id)
+        on delete cascade,
+    CONSTRAINT recitation_fk_mushaf_id FOREIGN KEY(mushaf_id) REFERENCES quran_mushafs(id)
+        on delete cascade
+);
+
+-- Audio file of a single ayah, duration is in milliseconds
+CREATE TABLE quran_recitation_ayahs (
+    id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    recitation_id serial NOT NULL,
+    ayah_id serial NOT NULL,
+    url TEXT NOT NULL,
+    duration INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_recitation_ayahs_id PRIMARY KEY (id),
+    CONSTRAINT quran_recitation_ayahs_ayah UNIQUE (recitation_id, ayah_id),
+    CONSTRAINT recitation_ayah_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT recitation_ayah_fk_recitation_id FOREIGN KEY(recitation_id) REFERENCES quran_recitations(id)
+        on delete cascade,
+    CONSTRAINT recitation_ayah_fk_ayah_id FOREIGN KEY(ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade
+);
+
+-- Time of a word in the ayah audio, in milliseconds from the start of the file
+CREATE TABLE quran_recitation_words (
+    id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    recitation_ayah_id serial NOT NULL,
+    word_id serial NOT NULL,
+    start_time INT NOT NULL,
+    end_time INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_recitation_words_id PRIMARY KEY (id),
+    CONSTRAINT quran_recitation_words_word UNIQUE (recitation_ayah_id, word_id),
+    CONSTRAINT quran_recitation_words_time CHECK (start_time >= 0 AND start_time <= end_time),
+    CONSTRAINT recitation_word_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT recitation_word_fk_recitation_ayah_id FOREIGN KEY(recitation_ayah_id) REFERENCES quran_recitation_ayahs(id)
+        on delete cascade,
+    CONSTRAINT recitation_word_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
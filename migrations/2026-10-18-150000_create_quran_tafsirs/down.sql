@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE quran_tafsir_entries;
+DROP TABLE quran_tafsirs;
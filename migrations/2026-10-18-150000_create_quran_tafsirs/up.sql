@@ -0,0 +1,42 @@
+CREATE TABLE quran_tafsirs (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    mushaf_id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    author_account_id serial NOT NULL,
+    name VARCHAR(300) NOT NULL,
+    language VARCHAR(5) NOT NULL,
+    source VARCHAR(300),
+    approved BOOLEAN DEFAULT FALSE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_tafsirs_id PRIMARY KEY (id),
+    CONSTRAINT tafsir_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT tafsir_fk_mushaf_id FOREIGN KEY(mushaf_id) REFERENCES quran_mushafs(id)
+        on delete cascade,
+    CONSTRAINT tafsir_fk_author_account_id FOREIGN KEY(author_account_id) REFERENCES app_accounts(id)
+);
+
+-- Commentary of a range of ayahs, start and end are both
+-- included and are in the same surah
+CREATE TABLE quran_tafsir_entries (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    tafsir_id serial NOT NULL,
+    start_ayah_id serial NOT NULL,
+    end_ayah_id serial NOT NULL,
+    text TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_tafsir_entries_id PRIMARY KEY (id),
+    CONSTRAINT tafsir_entry_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT tafsir_entry_fk_tafsir_id FOREIGN KEY(tafsir_id) REFERENCES quran_tafsirs(id)
+        on delete cascade,
+    CONSTRAINT tafsir_entry_fk_start_ayah_id FOREIGN KEY(start_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade,
+    CONSTRAINT tafsir_entry_fk_end_ayah_id FOREIGN KEY(end_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade
+);
+
+CREATE INDEX quran_tafsir_entries_start_ayah_idx ON quran_tafsir_entries (tafsir_id, start_ayah_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE quran_words DROP CONSTRAINT quran_words_word_number;
+ALTER TABLE quran_words DROP COLUMN word_number;
@@ -0,0 +1,16 @@
+ALTER TABLE quran_words ADD COLUMN word_number INT;
+
+-- Words had no position, the insertion order was the word order
+UPDATE quran_words SET word_number = numbered.word_number
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (PARTITION BY ayah_id ORDER BY id) AS word_number
+        FROM quran_words
+    ) AS numbered
+    WHERE quran_words.id = numbered.id;
+
+ALTER TABLE quran_words ALTER COLUMN word_number SET NOT NULL;
+
+-- Deferrable, so the words can be shifted with a single update
+-- and reordered in a transaction
+ALTER TABLE quran_words ADD CONSTRAINT quran_words_word_number
+    UNIQUE (ayah_id, word_number) DEFERRABLE INITIALLY IMMEDIATE;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE quran_ayahs DROP CONSTRAINT quran_ayahs_ayah_number;
+ALTER TABLE quran_surahs DROP CONSTRAINT quran_surahs_number;
@@ -0,0 +1,21 @@
+-- Deletes left gaps in the numbers, renumber them by the current order
+UPDATE quran_surahs SET number = numbered.number
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (PARTITION BY mushaf_id ORDER BY number, id) AS number
+        FROM quran_surahs
+    ) AS numbered
+    WHERE quran_surahs.id = numbered.id AND quran_surahs.number <> numbered.number;
+
+UPDATE quran_ayahs SET ayah_number = numbered.ayah_number
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (PARTITION BY surah_id ORDER BY ayah_number, id) AS ayah_number
+        FROM quran_ayahs
+    ) AS numbered
+    WHERE quran_ayahs.id = numbered.id AND quran_ayahs.ayah_number <> numbered.ayah_number;
+
+-- Deferrable, so the numbers can be shifted in a transaction
+ALTER TABLE quran_surahs ADD CONSTRAINT quran_surahs_number
+    UNIQUE (mushaf_id, number) DEFERRABLE INITIALLY IMMEDIATE;
+
+ALTER TABLE quran_ayahs ADD CONSTRAINT quran_ayahs_ayah_number
+    UNIQUE (surah_id, ayah_number) DEFERRABLE INITIALLY IMMEDIATE;
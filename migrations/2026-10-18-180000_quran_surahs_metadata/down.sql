@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE quran_surahs DROP CONSTRAINT quran_surahs_period;
+ALTER TABLE quran_surahs DROP COLUMN rukus;
+ALTER TABLE quran_surahs DROP COLUMN revelation_order;
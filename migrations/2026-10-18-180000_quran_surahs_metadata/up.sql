@@ -0,0 +1,19 @@
+ALTER TABLE quran_surahs ADD COLUMN revelation_order INT;
+ALTER TABLE quran_surahs ADD COLUMN rukus INT;
+
+-- The period was free text, keep only the known values
+UPDATE quran_surahs SET period = CASE
+        WHEN LOWER(TRIM(period)) IN ('makki', 'makkah', 'mecca', 'meccan', 'makkiyah') THEN 'makki'
+        WHEN LOWER(TRIM(period)) IN ('madani', 'madinah', 'medina', 'medinan', 'madaniyah') THEN 'madani'
+        ELSE NULL
+    END
+    WHERE period IS NOT NULL;
+
+ALTER TABLE quran_surahs ADD CONSTRAINT quran_surahs_period
+    CHECK (period IN ('makki', 'madani'));
+
+ALTER TABLE quran_surahs ADD CONSTRAINT quran_surahs_revelation_order
+    CHECK (revelation_order > 0);
+
+ALTER TABLE quran_surahs ADD CONSTRAINT quran_surahs_rukus
+    CHECK (rukus >= 0);
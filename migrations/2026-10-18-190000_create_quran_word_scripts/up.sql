@@ -0,0 +1,15 @@
+CREATE TABLE quran_word_scripts (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    word_id serial NOT NULL,
+    script VARCHAR(20) NOT NULL,
+    text TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_scripts_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_scripts_script UNIQUE (word_id, script),
+    CONSTRAINT word_script_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_script_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
@@ -0,0 +1,17 @@
+CREATE TABLE quran_word_tajweeds (
+    id serial NOT NULL,
+    creator_user_id serial NOT NULL,
+    word_id serial NOT NULL,
+    rule VARCHAR(30) NOT NULL,
+    start_index INT NOT NULL,
+    end_index INT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_word_tajweeds_id PRIMARY KEY (id),
+    CONSTRAINT quran_word_tajweeds_range CHECK (start_index >= 0 AND start_index < end_index),
+    CONSTRAINT word_tajweed_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT word_tajweed_fk_word_id FOREIGN KEY(word_id) REFERENCES quran_words(id)
+        on delete cascade
+);
+
+CREATE INDEX quran_word_tajweeds_word ON quran_word_tajweeds (word_id);
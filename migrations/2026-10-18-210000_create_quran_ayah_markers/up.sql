@@ -0,0 +1,14 @@
+CREATE TABLE quran_ayah_markers (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    ayah_id serial NOT NULL,
+    marker VARCHAR(30) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_ayah_markers_id PRIMARY KEY (id),
+    CONSTRAINT quran_ayah_markers_marker UNIQUE (ayah_id, marker),
+    CONSTRAINT ayah_marker_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT ayah_marker_fk_ayah_id FOREIGN KEY(ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade
+);
@@ -0,0 +1,17 @@
+-- This file should undo anything in `up.sql`
+
+-- The signs are embedded back in the words
+--
+-- Not a full undo, the words of only waqf signs that up.sql deleted
+-- are not recreated and the word numbers stay as they are
+UPDATE quran_words SET word = word || CASE waqf
+        WHEN 'sala' THEN U&'\06D6'
+        WHEN 'qala' THEN U&'\06D7'
+        WHEN 'lazim' THEN U&'\06D8'
+        WHEN 'la' THEN U&'\06D9'
+        WHEN 'jaiz' THEN U&'\06DA'
+        WHEN 'muanaqah' THEN U&'\06DB'
+    END
+    WHERE waqf IS NOT NULL;
+
+ALTER TABLE quran_words DROP COLUMN waqf;
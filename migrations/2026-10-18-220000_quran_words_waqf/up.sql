@@ -0,0 +1,46 @@
+ALTER TABLE quran_words ADD COLUMN waqf VARCHAR(10);
+
+ALTER TABLE quran_words ADD CONSTRAINT quran_words_waqf
+    CHECK (waqf IN ('sala', 'qala', 'lazim', 'la', 'jaiz', 'muanaqah'));
+
+-- Name of the last waqf sign (U+06D6 to U+06DB) in the text
+CREATE FUNCTION quran_waqf_name(text TEXT) RETURNS VARCHAR(10) AS $$
+    SELECT CASE right(regexp_replace($1, U&'[^\06D6-\06DB]', '', 'g'), 1)
+        WHEN U&'\06D6' THEN 'sala'
+        WHEN U&'\06D7' THEN 'qala'
+        WHEN U&'\06D8' THEN 'lazim'
+        WHEN U&'\06D9' THEN 'la'
+        WHEN U&'\06DA' THEN 'jaiz'
+        WHEN U&'\06DB' THEN 'muanaqah'
+    END
+$$ LANGUAGE SQL IMMUTABLE;
+
+-- Signs embedded in the words
+UPDATE quran_words
+    SET waqf = quran_waqf_name(word), word = regexp_replace(word, U&'[\06D6-\06DB]', '', 'g')
+    WHERE word ~ U&'[\06D6-\06DB]' AND word !~ U&'^[\06D6-\06DB]+$';
+
+-- Words of only waqf signs (tanzil uthmani text) are the sign of the word before them
+UPDATE quran_words SET waqf = signs.waqf
+    FROM (
+        SELECT ayah_id, word_number, quran_waqf_name(word) AS waqf
+        FROM quran_words
+        WHERE word ~ U&'^[\06D6-\06DB]+$'
+    ) AS signs
+    WHERE quran_words.ayah_id = signs.ayah_id
+        AND quran_words.word_number = signs.word_number - 1;
+
+-- IRREVERSIBLE: the sign words are deleted, with everything that references them
+-- (translations, layouts, morphology, tajweed and timings cascade) and the words
+-- after them are renumbered. down.sql embeds the signs in the words before them,
+-- it does not recreate these rows or the old numbering
+DELETE FROM quran_words WHERE word ~ U&'^[\06D6-\06DB]+$' AND word_number > 1;
+
+UPDATE quran_words SET word_number = numbered.word_number
+    FROM (
+        SELECT id, ROW_NUMBER() OVER (PARTITION BY ayah_id ORDER BY word_number, id) AS word_number
+        FROM quran_words
+    ) AS numbered
+    WHERE quran_words.id = numbered.id AND quran_words.word_number <> numbered.word_number;
+
+DROP FUNCTION quran_waqf_name;
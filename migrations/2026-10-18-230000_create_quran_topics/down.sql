@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE quran_topic_ayahs;
+DROP TABLE quran_topics;
@@ -0,0 +1,41 @@
+-- Topics are a tree, the name is a phrase so it can be
+-- translated like the other phrases
+CREATE TABLE quran_topics (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    parent_id INT,
+    name_phrase TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_topics_id PRIMARY KEY (id),
+    CONSTRAINT topic_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT topic_fk_parent_id FOREIGN KEY(parent_id) REFERENCES quran_topics(id)
+        on delete cascade,
+    CONSTRAINT topic_fk_name_phrase FOREIGN KEY(name_phrase) REFERENCES app_phrases(phrase)
+);
+
+CREATE INDEX quran_topics_parent_idx ON quran_topics (parent_id);
+
+-- Ayah ranges of a topic, start and end are both
+-- included and are in the same surah
+CREATE TABLE quran_topic_ayahs (
+    id serial NOT NULL,
+    uuid uuid DEFAULT uuid_generate_v4 () NOT NULL,
+    creator_user_id serial NOT NULL,
+    topic_id serial NOT NULL,
+    start_ayah_id serial NOT NULL,
+    end_ayah_id serial NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT quran_topic_ayahs_id PRIMARY KEY (id),
+    CONSTRAINT topic_ayah_fk_user_id_rel FOREIGN KEY(creator_user_id) REFERENCES app_users(id),
+    CONSTRAINT topic_ayah_fk_topic_id FOREIGN KEY(topic_id) REFERENCES quran_topics(id)
+        on delete cascade,
+    CONSTRAINT topic_ayah_fk_start_ayah_id FOREIGN KEY(start_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade,
+    CONSTRAINT topic_ayah_fk_end_ayah_id FOREIGN KEY(end_ayah_id) REFERENCES quran_ayahs(id)
+        on delete cascade
+);
+
+CREATE INDEX quran_topic_ayahs_topic_idx ON quran_topic_ayahs (topic_id);
@@ -0,0 +1,112 @@
+/// Buckwalter transliteration table, with the extended quranic
+/// marks of the Quranic Arabic Corpus
+const BUCKWALTER: &[(char, char)] = &[
+    ('\'', '\u{0621}'),
+    ('|', '\u{0622}'),
+    ('>', '\u{0623}'),
+    ('&', '\u{0624}'),
+    ('<', '\u{0625}'),
+    ('}', '\u{0626}'),
+    ('A', '\u{0627}'),
+    ('b', '\u{0628}'),
+    ('p', '\u{0629}'),
+    ('t', '\u{062A}'),
+    ('v', '\u{062B}'),
+    ('j', '\u{062C}'),
+    ('H', '\u{062D}'),
+    ('x', '\u{062E}'),
+    ('d', '\u{062F}'),
+    ('*', '\u{0630}'),
+    ('r', '\u{0631}'),
+    ('z', '\u{0632}'),
+    ('s', '\u{0633}'),
+    ('$', '\u{0634}'),
+    ('S', '\u{0635}'),
+    ('D', '\u{0636}'),
+    ('T', '\u{0637}'),
+    ('Z', '\u{0638}'),
+    ('E', '\u{0639}'),
+    ('g', '\u{063A}'),
+    ('_', '\u{0640}'),
+    ('f', '\u{0641}'),
+    ('q', '\u{0642}'),
+    ('k', '\u{0643}'),
+    ('l', '\u{0644}'),
+    ('m', '\u{0645}'),
+    ('n', '\u{0646}'),
+    ('h', '\u{0647}'),
+    ('w', '\u{0648}'),
+    ('Y', '\u{0649}'),
+    ('y', '\u{064A}'),
+    ('F', '\u{064B}'),
+    ('N', '\u{064C}'),
+    ('K', '\u{064D}'),
+    ('a', '\u{064E}'),
+    ('u', '\u{064F}'),
+    ('i', '\u{0650}'),
+    ('~', '\u{0651}'),
+    ('o', '\u{0652}'),
+    ('^', '\u{0653}'),
+    ('#', '\u{0654}'),
+    ('`', '\u{0670}'),
+    ('{', '\u{0671}'),
+    (':', '\u{06DC}'),
+    ('@', '\u{06DF}'),
+    ('"', '\u{06E0}'),
+    ('[', '\u{06E2}'),
+    (';', '\u{06E3}'),
+    (',', '\u{06E5}'),
+    ('.', '\u{06E6}'),
+    ('!', '\u{06E8}'),
+    ('-', '\u{06EA}'),
+    ('+', '\u{06EB}'),
+    ('%', '\u{06EC}'),
+    (']', '\u{06ED}'),
+];
+
+/// Converts Buckwalter transliterated text to the arabic script,
+/// unknown characters are kept as they are
+pub fn to_arabic(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            BUCKWALTER
+                .iter()
+                .find(|(latin, _)| *latin == c)
+                .map_or(c, |(_, arabic)| *arabic)
+        })
+        .collect()
+}
+
+/// Converts arabic text to the Buckwalter transliteration,
+/// unknown characters are kept as they are
+pub fn to_buckwalter(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            BUCKWALTER
+                .iter()
+                .find(|(_, arabic)| *arabic == c)
+                .map_or(c, |(latin, _)| *latin)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_arabic() {
+        assert_eq!(to_arabic("smw"), "سمو");
+        assert_eq!(
+            to_arabic("{ll~ah"),
+            "\u{0671}\u{0644}\u{0644}\u{0651}\u{064E}\u{0647}"
+        );
+        assert_eq!(to_arabic("bi {som"), "بِ ٱسْم");
+    }
+
+    #[test]
+    fn test_to_buckwalter() {
+        assert_eq!(to_buckwalter("بِسْمِ ٱللَّهِ"), "bisomi {lla~hi");
+        assert_eq!(to_arabic(&to_buckwalter("ٱلرَّحْمَٰنِ")), "ٱلرَّحْمَٰنِ");
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+use auth_n::HashBuilder;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+
+use crate::routers::multip;
+use crate::routers::quran::word::{with_waqf, WaqfMark};
+
+/// Surah id, ayah number and sajdah of an ayah
+type ChecksumAyah = (i32, i32, Option<String>);
+
+/// Text and waqf sign of a word, none for an ayah without words
+type ChecksumWord = (Option<String>, Option<String>);
+
+/// A single line of the checksum source, the ayah number,
+/// sajdah and the words
+fn ayah_line(number: i32, sajdah: Option<&str>, words: &[String]) -> String {
+    format!(
+        "{}|{}|{}\n",
+        number,
+        sajdah.unwrap_or_default(),
+        words.join(" ")
+    )
+}
+
+fn hash(source: String) -> String {
+    HashBuilder::default()
+        .set_source(&source.into_bytes())
+        .generate()
+        .get_result()
+        .unwrap()
+}
+
+/// Returns the SHA-256 of the surahs by surah id
+///
+/// Source of a surah is one `number|sajdah|words` line for
+/// each ayah in order, words have their waqf sign
+pub fn surah_checksums(
+    conn: &mut PgConnection,
+    surahs: &[i32],
+) -> QueryResult<HashMap<i32, String>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, sajdah, surah_id};
+    use crate::schema::quran_words::dsl::{quran_words, waqf, word, word_number};
+
+    // Left join, so an ayah without words is still in the source
+    let rows: Vec<(ChecksumAyah, ChecksumWord)> = quran_ayahs
+        .left_join(quran_words)
+        .filter(surah_id.eq_any(surahs))
+        .order((surah_id.asc(), ayah_number.asc(), word_number.asc()))
+        .select((
+            (surah_id, ayah_number, sajdah),
+            (word.nullable(), waqf.nullable()),
+        ))
+        .load(conn)?;
+
+    let mut sources: HashMap<i32, String> = HashMap::new();
+
+    for ((surah, number, ayah_sajdah), ayah_words) in multip(rows, |ayah| ayah) {
+        let words: Vec<String> = ayah_words
+            .into_iter()
+            .filter_map(|(text, mark)| Some(with_waqf(text?, WaqfMark::from_option_string(mark))))
+            .collect();
+
+        sources.entry(surah).or_default().push_str(&ayah_line(
+            number,
+            ayah_sajdah.as_deref(),
+            &words,
+        ));
+    }
+
+    Ok(surahs
+        .iter()
+        .map(|surah| (*surah, hash(sources.remove(surah).unwrap_or_default())))
+        .collect())
+}
+
+/// Returns the SHA-256 of the mushaf
+///
+/// Source of the mushaf is one `number|checksum` line for
+/// each surah in order
+pub fn mushaf_checksum(conn: &mut PgConnection, mushaf: i32) -> QueryResult<String> {
+    use crate::schema::quran_surahs::dsl::{id, mushaf_id, number, quran_surahs};
+
+    let surahs: Vec<(i32, i32)> = quran_surahs
+        .filter(mushaf_id.eq(mushaf))
+        .order(number.asc())
+        .select((id, number))
+        .load(conn)?;
+
+    let ids: Vec<i32> = surahs.iter().map(|(surah, _)| *surah).collect();
+    let mut checksums = surah_checksums(conn, &ids)?;
+
+    Ok(hash(
+        surahs
+            .into_iter()
+            .map(|(surah, surah_number)| {
+                format!("{}|{}\n", surah_number, checksums.remove(&surah).unwrap())
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ayah_line() {
+        let words = vec!["الم".to_string(), "ذلك".to_string()];
+
+        assert_eq!(ayah_line(1, None, &words), "1||الم ذلك\n");
+        assert_eq!(ayah_line(15, Some("vajib"), &words), "15|vajib|الم ذلك\n");
+    }
+}
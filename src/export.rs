@@ -0,0 +1,317 @@
+use quick_xml::escape::escape;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::routers::quran::surah::{AyahTy, Format, SimpleAyah};
+
+/// The file formats that a mushaf can be exported to
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    Json,
+
+    /// Tanzil quran xml, can be imported with the xml import
+    Xml,
+
+    /// `surah,ayah,sajdah,text` or `surah,ayah,sajdah,word` rows,
+    /// can be imported with the csv import
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Xml => "application/xml; charset=utf-8",
+            Self::Csv => "text/csv; charset=utf-8",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Xml => "xml",
+            Self::Csv => "csv",
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ExportMushaf {
+    pub uuid: Uuid,
+    pub short_name: Option<String>,
+    pub name: Option<String>,
+    pub source: Option<String>,
+    pub bismillah_text: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ExportSurah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub name: String,
+    pub name_transliteration: Option<String>,
+    pub period: Option<String>,
+    pub bismillah_status: bool,
+    pub bismillah_as_first_ayah: bool,
+    pub ayahs: Vec<AyahTy>,
+}
+
+/// Writes the exported mushaf piece by piece
+///
+/// The output is `begin`, `surah` for every surah in order, then `end`.
+/// So the response can be streamed surah by surah
+pub struct ExportWriter<'a> {
+    format: ExportFormat,
+    text_format: Format,
+    mushaf: &'a ExportMushaf,
+}
+
+fn ayah_parts(ayah: &AyahTy) -> (&SimpleAyah, Vec<&str>) {
+    match ayah {
+        AyahTy::Text(a) => (&a.ayah, vec![a.text.as_str()]),
+        AyahTy::Words(a) => (&a.ayah, a.words.iter().map(|w| w.as_str()).collect()),
+    }
+}
+
+impl<'a> ExportWriter<'a> {
+    pub fn new(format: ExportFormat, text_format: Format, mushaf: &'a ExportMushaf) -> Self {
+        Self {
+            format,
+            text_format,
+            mushaf,
+        }
+    }
+
+    /// Everything that comes before the first surah
+    pub fn begin(&self) -> String {
+        match self.format {
+            ExportFormat::Json => format!(
+                "{{\"mushaf\":{},\"surahs\":[",
+                serde_json::to_string(self.mushaf).unwrap()
+            ),
+
+            ExportFormat::Xml => format!(
+                "<?xml version=\"1.0\" encoding=\"utf-8\" ?>\n<quran short_name=\"{}\" name=\"{}\" source=\"{}\">\n",
+                escape(self.mushaf.short_name.as_deref().unwrap_or_default()),
+                escape(self.mushaf.name.as_deref().unwrap_or_default()),
+                escape(self.mushaf.source.as_deref().unwrap_or_default()),
+            ),
+
+            ExportFormat::Csv => match self.text_format {
+                Format::Text => "surah,ayah,sajdah,text\n".to_string(),
+                Format::Word => "surah,ayah,sajdah,word\n".to_string(),
+            },
+        }
+    }
+
+    /// A single surah, index is the position of surah in the export
+    pub fn surah(&self, index: usize, surah: &ExportSurah) -> String {
+        match self.format {
+            ExportFormat::Json => {
+                let json = serde_json::to_string(surah).unwrap();
+
+                if index == 0 {
+                    json
+                } else {
+                    format!(",{}", json)
+                }
+            }
+
+            ExportFormat::Xml => self.surah_xml(surah),
+
+            ExportFormat::Csv => self.surah_csv(surah),
+        }
+    }
+
+    /// Everything that comes after the last surah
+    pub fn end(&self) -> String {
+        match self.format {
+            ExportFormat::Json => "]}".to_string(),
+            ExportFormat::Xml => "</quran>\n".to_string(),
+            ExportFormat::Csv => String::new(),
+        }
+    }
+
+    fn surah_xml(&self, surah: &ExportSurah) -> String {
+        let mut result = format!(
+            "  <sura index=\"{}\" name=\"{}\"",
+            surah.number,
+            escape(&surah.name)
+        );
+
+        if let Some(ref transliteration) = surah.name_transliteration {
+            result.push_str(&format!(" tname=\"{}\"", escape(transliteration)));
+        }
+
+        result.push_str(">\n");
+
+        for (index, ayah) in surah.ayahs.iter().enumerate() {
+            let (simple_ayah, words) = ayah_parts(ayah);
+
+            result.push_str(&format!("    <aya index=\"{}\"", simple_ayah.number));
+
+            if let Some(ref sajdah) = simple_ayah.sajdah {
+                result.push_str(&format!(" sajdah=\"{}\"", escape(sajdah)));
+            }
+
+            // Same as tanzil, the bismillah is on the first ayah of surah
+            if index == 0 && surah.bismillah_status && !surah.bismillah_as_first_ayah {
+                if let Some(ref bismillah) = self.mushaf.bismillah_text {
+                    result.push_str(&format!(" bismillah=\"{}\"", escape(bismillah)));
+                }
+            }
+
+            match ayah {
+                AyahTy::Text(_) => {
+                    result.push_str(&format!(" text=\"{}\" />\n", escape(words[0])));
+                }
+
+                AyahTy::Words(_) => {
+                    result.push_str(">\n");
+
+                    for word in words {
+                        result.push_str(&format!("      <word>{}</word>\n", escape(word)));
+                    }
+
+                    result.push_str("    </aya>\n");
+                }
+            }
+        }
+
+        result.push_str("  </sura>\n");
+
+        result
+    }
+
+    fn surah_csv(&self, surah: &ExportSurah) -> String {
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(vec![]);
+
+        for ayah in surah.ayahs.iter() {
+            let (simple_ayah, words) = ayah_parts(ayah);
+
+            let surah_number = surah.number.to_string();
+            let ayah_number = simple_ayah.number.to_string();
+            let sajdah = simple_ayah.sajdah.as_deref().unwrap_or_default();
+
+            for word in words {
+                writer
+                    .write_record([surah_number.as_str(), ayah_number.as_str(), sajdah, word])
+                    .unwrap();
+            }
+        }
+
+        String::from_utf8(writer.into_inner().unwrap()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::import::{parse_csv, parse_xml};
+    use crate::routers::quran::surah::{AyahWithText, AyahWithWords};
+
+    fn mushaf() -> ExportMushaf {
+        ExportMushaf {
+            uuid: Uuid::nil(),
+            short_name: Some("test".to_string()),
+            name: None,
+            source: None,
+            bismillah_text: Some("بسم الله".to_string()),
+        }
+    }
+
+    fn surah(text_format: &Format) -> ExportSurah {
+        let ayahs = [(1, None, "الم"), (2, Some("vajib"), "ذلك الكتاب")]
+            .into_iter()
+            .map(|(number, sajdah, text)| {
+                let ayah = SimpleAyah {
+                    number,
+                    uuid: Uuid::nil(),
+                    sajdah: sajdah.map(|s: &str| s.to_string()),
+                };
+
+                match text_format {
+                    Format::Text => AyahTy::Text(AyahWithText {
+                        ayah,
+                        text: text.to_string(),
+                        transliteration: None,
+                        audio: None,
+                    }),
+                    Format::Word => AyahTy::Words(AyahWithWords {
+                        ayah,
+                        words: text.split(' ').map(|w| w.to_string()).collect(),
+                        morphology: None,
+                        translations: None,
+                        tajweed: None,
+                        waqf: None,
+                        transliteration: None,
+                        audio: None,
+                    }),
+                }
+            })
+            .collect();
+
+        ExportSurah {
+            uuid: Uuid::nil(),
+            number: 2,
+            name: "البقرة".to_string(),
+            name_transliteration: None,
+            period: None,
+            bismillah_status: true,
+            bismillah_as_first_ayah: false,
+            ayahs,
+        }
+    }
+
+    fn export(format: ExportFormat, text_format: Format) -> String {
+        let mushaf = mushaf();
+        let surah = surah(&text_format);
+        let writer = ExportWriter::new(format, text_format, &mushaf);
+
+        format!(
+            "{}{}{}",
+            writer.begin(),
+            writer.surah(0, &surah),
+            writer.end()
+        )
+    }
+
+    #[test]
+    fn test_export_json() {
+        let json: serde_json::Value =
+            serde_json::from_str(&export(ExportFormat::Json, Format::Text)).unwrap();
+
+        assert_eq!(json["surahs"][0]["ayahs"][1]["text"], "ذلك الكتاب");
+        assert_eq!(json["surahs"][0]["ayahs"][1]["sajdah"], "vajib");
+    }
+
+    #[test]
+    fn test_export_xml_round_trip() {
+        for text_format in [Format::Text, Format::Word] {
+            let mut parsed = parse_xml(&export(ExportFormat::Xml, text_format)).unwrap();
+            parsed.resolve_bismillah(None);
+
+            assert_eq!(parsed.bismillah_text, Some("بسم الله".to_string()));
+
+            let surah = &parsed.surahs[0];
+            assert_eq!(surah.name, "البقرة");
+            assert!(surah.bismillah_status);
+            assert_eq!(surah.ayahs[1].words, vec!["ذلك", "الكتاب"]);
+        }
+    }
+
+    #[test]
+    fn test_export_csv_round_trip() {
+        for text_format in [Format::Text, Format::Word] {
+            let parsed = parse_csv(&export(ExportFormat::Csv, text_format)).unwrap();
+
+            let surah = &parsed.surahs[0];
+            assert_eq!(surah.number, 2);
+            assert_eq!(surah.ayahs[0].words, vec!["الم"]);
+            assert_eq!(surah.ayahs[1].words, vec!["ذلك", "الكتاب"]);
+        }
+    }
+}
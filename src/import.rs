@@ -0,0 +1,788 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io;
+
+use diesel::dsl::exists;
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::reader::Reader;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::RouterError;
+use crate::models::{
+    NewQuranAyah, NewQuranMushaf, NewQuranSurah, NewQuranWord, QuranAyah, QuranMushaf, QuranSurah,
+};
+use crate::routers::quran::ayah::Sajdah;
+use crate::routers::quran::word::{separate_waqf, WaqfWord};
+use crate::routers::quran::MUSHAF_INSERT_CHUNK;
+use crate::DbPool;
+
+/// The file formats that can be imported
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportFormat {
+    /// Tanzil quran xml, `<sura index name><aya index text/></sura>`
+    Xml,
+
+    /// Tanzil plain text, one `surah|ayah|text` per line
+    Text,
+
+    /// `surah,ayah,sajdah,text` rows, or `surah,ayah,sajdah,word`
+    /// with one row per word (see the mushaf export)
+    Csv,
+}
+
+impl TryFrom<&str> for ImportFormat {
+    type Error = ImportError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "xml" => Ok(Self::Xml),
+            "text" => Ok(Self::Text),
+            "csv" => Ok(Self::Csv),
+
+            _ => Err(ImportError::UnknownFormat(value.to_string())),
+        }
+    }
+}
+
+/// Errors that can happen while parsing the import file
+#[derive(Debug, PartialEq)]
+pub enum ImportError {
+    UnknownFormat(String),
+    Xml(String),
+    Csv(String),
+    InvalidCsvHeader,
+    MissingAttribute(&'static str, &'static str),
+    InvalidNumber(String),
+    InvalidSajdah(String),
+    InvalidLine(usize),
+    AyahOutsideSurah(i32),
+    WordOutsideAyah,
+    DuplicateSurah(i32),
+    DuplicateAyah(i32, i32),
+    EmptyAyah(i32, i32),
+    Empty,
+}
+
+impl Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownFormat(format) => write!(f, "unknown import format '{}'", format),
+            Self::Xml(err) => write!(f, "xml error: {}", err),
+            Self::Csv(err) => write!(f, "csv error: {}", err),
+            Self::InvalidCsvHeader => write!(
+                f,
+                "csv header must be surah,ayah,sajdah,text or surah,ayah,sajdah,word"
+            ),
+            Self::MissingAttribute(element, attr) => {
+                write!(f, "<{}> element has no '{}' attribute", element, attr)
+            }
+            Self::InvalidNumber(value) => write!(f, "'{}' is not a valid number", value),
+            Self::InvalidSajdah(value) => write!(f, "'{}' is not a valid sajdah type", value),
+            Self::InvalidLine(line) => write!(f, "line {} is not in surah|ayah|text format", line),
+            Self::AyahOutsideSurah(ayah) => write!(f, "ayah {} is not inside a surah", ayah),
+            Self::WordOutsideAyah => write!(f, "<word> element is not inside an <aya>"),
+            Self::DuplicateSurah(surah) => write!(f, "surah {} is defined twice", surah),
+            Self::DuplicateAyah(surah, ayah) => {
+                write!(f, "ayah {}:{} is defined twice", surah, ayah)
+            }
+            Self::EmptyAyah(surah, ayah) => write!(f, "ayah {}:{} has no words", surah, ayah),
+            Self::Empty => write!(f, "file does not contain any ayah"),
+        }
+    }
+}
+
+impl From<quick_xml::Error> for ImportError {
+    fn from(value: quick_xml::Error) -> Self {
+        Self::Xml(value.to_string())
+    }
+}
+
+impl From<csv::Error> for ImportError {
+    fn from(value: csv::Error) -> Self {
+        Self::Csv(value.to_string())
+    }
+}
+
+impl From<ImportError> for RouterError {
+    fn from(value: ImportError) -> Self {
+        Self::from_predefined_with_detail("IMPORT_PARSE_ERROR", &value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedAyah {
+    pub number: i32,
+    pub sajdah: Option<Sajdah>,
+    pub words: Vec<String>,
+
+    /// Bismillah text that comes before this ayah (tanzil `bismillah` attribute)
+    pub bismillah: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSurah {
+    pub number: i32,
+    pub name: String,
+    pub name_transliteration: Option<String>,
+    pub bismillah_status: bool,
+    pub bismillah_as_first_ayah: bool,
+    pub ayahs: Vec<ParsedAyah>,
+}
+
+/// The whole mushaf read from the import file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedMushaf {
+    pub bismillah_text: Option<String>,
+    pub surahs: Vec<ParsedSurah>,
+}
+
+/// What the import has created
+#[derive(Serialize, Debug)]
+pub struct ImportReport {
+    pub mushaf_uuid: Uuid,
+    pub surahs: usize,
+    pub ayahs: usize,
+    pub words: usize,
+}
+
+/// The new mushaf info, the content comes from the import file
+pub struct ImportMushaf<'a> {
+    pub short_name: &'a str,
+    pub name: Option<&'a str>,
+    pub source: Option<&'a str>,
+    pub bismillah_text: Option<String>,
+}
+
+fn split_words(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|w| w.to_string()).collect()
+}
+
+fn parse_number(value: &str) -> Result<i32, ImportError> {
+    value
+        .trim()
+        .parse()
+        .map_err(|_| ImportError::InvalidNumber(value.to_string()))
+}
+
+/// Accepts our own sajdah names and the tanzil metadata ones
+fn parse_sajdah(value: &str) -> Result<Sajdah, ImportError> {
+    match value.trim() {
+        "vajib" | "obligatory" => Ok(Sajdah::Vajib),
+        "mostahab" | "recommended" => Ok(Sajdah::Mostahab),
+
+        _ => Err(ImportError::InvalidSajdah(value.to_string())),
+    }
+}
+
+fn get_attribute(element: &BytesStart, name: &'static str) -> Result<Option<String>, ImportError> {
+    match element.try_get_attribute(name)? {
+        Some(attr) => Ok(Some(attr.unescape_value()?.into_owned())),
+        None => Ok(None),
+    }
+}
+
+fn require_attribute(
+    element: &BytesStart,
+    element_name: &'static str,
+    name: &'static str,
+) -> Result<String, ImportError> {
+    get_attribute(element, name)?.ok_or(ImportError::MissingAttribute(element_name, name))
+}
+
+/// Parse the tanzil xml format
+///
+/// `<sajda sura aya type/>` elements of the tanzil metadata are also
+/// accepted in the same document and set the ayah sajdah
+///
+/// Instead of the `text` attribute an `<aya>` can have `<word>` children
+pub fn parse_xml(content: &str) -> Result<ParsedMushaf, ImportError> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+
+    let mut surahs: Vec<ParsedSurah> = vec![];
+    let mut sajdahs: Vec<(i32, i32, Sajdah)> = vec![];
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(element) | Event::Empty(element) => match element.name().as_ref() {
+                b"sura" => {
+                    let number = parse_number(&require_attribute(&element, "sura", "index")?)?;
+
+                    if surahs.iter().any(|s| s.number == number) {
+                        return Err(ImportError::DuplicateSurah(number));
+                    }
+
+                    surahs.push(ParsedSurah {
+                        number,
+                        name: get_attribute(&element, "name")?.unwrap_or_default(),
+                        name_transliteration: get_attribute(&element, "tname")?,
+                        bismillah_status: false,
+                        bismillah_as_first_ayah: false,
+                        ayahs: vec![],
+                    });
+                }
+
+                b"aya" => {
+                    let number = parse_number(&require_attribute(&element, "aya", "index")?)?;
+                    let text = get_attribute(&element, "text")?;
+
+                    let sajdah = match get_attribute(&element, "sajdah")? {
+                        Some(value) => Some(parse_sajdah(&value)?),
+                        None => None,
+                    };
+
+                    let Some(surah) = surahs.last_mut() else {
+                        return Err(ImportError::AyahOutsideSurah(number));
+                    };
+
+                    if surah.ayahs.iter().any(|a| a.number == number) {
+                        return Err(ImportError::DuplicateAyah(surah.number, number));
+                    }
+
+                    surah.ayahs.push(ParsedAyah {
+                        number,
+                        sajdah,
+                        words: text.as_deref().map(split_words).unwrap_or_default(),
+                        bismillah: get_attribute(&element, "bismillah")?,
+                    });
+                }
+
+                b"word" => {
+                    let word = reader.read_text(element.name())?;
+
+                    let Some(ayah) = surahs.last_mut().and_then(|s| s.ayahs.last_mut()) else {
+                        return Err(ImportError::WordOutsideAyah);
+                    };
+
+                    ayah.words.push(word.trim().to_string());
+                }
+
+                b"sajda" => {
+                    let surah = parse_number(&require_attribute(&element, "sajda", "sura")?)?;
+                    let ayah = parse_number(&require_attribute(&element, "sajda", "aya")?)?;
+                    let sajdah = parse_sajdah(&require_attribute(&element, "sajda", "type")?)?;
+
+                    sajdahs.push((surah, ayah, sajdah));
+                }
+
+                _ => {}
+            },
+
+            Event::Eof => break,
+
+            _ => {}
+        }
+    }
+
+    for (surah_number, ayah_number, sajdah) in sajdahs {
+        if let Some(ayah) = surahs
+            .iter_mut()
+            .filter(|s| s.number == surah_number)
+            .flat_map(|s| s.ayahs.iter_mut())
+            .find(|a| a.number == ayah_number)
+        {
+            ayah.sajdah = Some(sajdah);
+        }
+    }
+
+    let mut mushaf = ParsedMushaf {
+        bismillah_text: None,
+        surahs,
+    };
+
+    mushaf.check()?;
+
+    Ok(mushaf)
+}
+
+/// Collects the ayahs of the row based formats (text and csv)
+#[derive(Default)]
+struct AyahRows {
+    surahs: BTreeMap<i32, Vec<ParsedAyah>>,
+}
+
+impl AyahRows {
+    /// Adds an ayah row, with `append` the words of a row with the same
+    /// ayah as the previous row are added to that ayah
+    fn push(
+        &mut self,
+        surah_number: i32,
+        ayah_number: i32,
+        sajdah: Option<Sajdah>,
+        words: Vec<String>,
+        append: bool,
+    ) -> Result<(), ImportError> {
+        let ayahs = self.surahs.entry(surah_number).or_default();
+
+        if let Some(last) = ayahs.last_mut() {
+            if append && last.number == ayah_number {
+                last.words.extend(words);
+
+                return Ok(());
+            }
+        }
+
+        if ayahs.iter().any(|a| a.number == ayah_number) {
+            return Err(ImportError::DuplicateAyah(surah_number, ayah_number));
+        }
+
+        ayahs.push(ParsedAyah {
+            number: ayah_number,
+            sajdah,
+            words,
+            bismillah: None,
+        });
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ParsedMushaf, ImportError> {
+        let mut mushaf = ParsedMushaf {
+            bismillah_text: None,
+            surahs: self
+                .surahs
+                .into_iter()
+                .map(|(number, ayahs)| ParsedSurah {
+                    number,
+                    // Row based formats have no surah metadata
+                    name: String::new(),
+                    name_transliteration: None,
+                    bismillah_status: false,
+                    bismillah_as_first_ayah: false,
+                    ayahs,
+                })
+                .collect(),
+        };
+
+        mushaf.check()?;
+
+        Ok(mushaf)
+    }
+}
+
+fn parse_optional_sajdah(value: &str) -> Result<Option<Sajdah>, ImportError> {
+    if value.trim().is_empty() {
+        Ok(None)
+    } else {
+        parse_sajdah(value).map(Some)
+    }
+}
+
+/// Parse the tanzil text format
+///
+/// Each line is `surah|ayah|text` with an optional `|sajdah` at the end,
+/// empty lines and `#` comments are skipped
+pub fn parse_text(content: &str) -> Result<ParsedMushaf, ImportError> {
+    let mut rows = AyahRows::default();
+
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+
+        let (surah_number, ayah_number, text, sajdah) = match parts.as_slice() {
+            [surah, ayah, text] => (surah, ayah, text, None),
+            [surah, ayah, text, sajdah] => (surah, ayah, text, parse_optional_sajdah(sajdah)?),
+
+            _ => return Err(ImportError::InvalidLine(index + 1)),
+        };
+
+        rows.push(
+            parse_number(surah_number)?,
+            parse_number(ayah_number)?,
+            sajdah,
+            split_words(text),
+            false,
+        )?;
+    }
+
+    rows.finish()
+}
+
+/// Parse the csv format of the mushaf export
+///
+/// The header is `surah,ayah,sajdah,text` for one row per ayah, or
+/// `surah,ayah,sajdah,word` for one row per word
+pub fn parse_csv(content: &str) -> Result<ParsedMushaf, ImportError> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+
+    let per_word = match reader.headers()?.iter().collect::<Vec<&str>>().as_slice() {
+        ["surah", "ayah", "sajdah", "text"] => false,
+        ["surah", "ayah", "sajdah", "word"] => true,
+
+        _ => return Err(ImportError::InvalidCsvHeader),
+    };
+
+    let mut rows = AyahRows::default();
+
+    for record in reader.records() {
+        let record = record?;
+
+        rows.push(
+            parse_number(&record[0])?,
+            parse_number(&record[1])?,
+            parse_optional_sajdah(&record[2])?,
+            split_words(&record[3]),
+            per_word,
+        )?;
+    }
+
+    rows.finish()
+}
+
+impl ParsedMushaf {
+    /// Parse the content with the requested format
+    pub fn parse(format: &ImportFormat, content: &str) -> Result<Self, ImportError> {
+        match format {
+            ImportFormat::Xml => parse_xml(content),
+            ImportFormat::Text => parse_text(content),
+            ImportFormat::Csv => parse_csv(content),
+        }
+    }
+
+    fn check(&mut self) -> Result<(), ImportError> {
+        if self.surahs.iter().all(|s| s.ayahs.is_empty()) {
+            return Err(ImportError::Empty);
+        }
+
+        for surah in self.surahs.iter_mut() {
+            surah.ayahs.sort_by_key(|a| a.number);
+
+            if let Some(ayah) = surah.ayahs.iter().find(|a| a.words.is_empty()) {
+                return Err(ImportError::EmptyAyah(surah.number, ayah.number));
+            }
+        }
+
+        self.surahs.sort_by_key(|s| s.number);
+
+        Ok(())
+    }
+
+    /// Sets the bismillah fields of mushaf and surahs
+    ///
+    /// The mushaf bismillah is the `bismillah_text` if provided, otherwise the first
+    /// bismillah found in the file. A surah that its first ayah is exactly the bismillah
+    /// text gets the bismillah as first ayah.
+    ///
+    /// Text files don't mark the bismillah, in that case every surah except
+    /// At-Tawbah (9) has the bismillah.
+    pub fn resolve_bismillah(&mut self, bismillah_text: Option<String>) {
+        let has_markers = self
+            .surahs
+            .iter()
+            .flat_map(|s| s.ayahs.iter())
+            .any(|a| a.bismillah.is_some());
+
+        self.bismillah_text = bismillah_text.or_else(|| {
+            self.surahs
+                .iter()
+                .flat_map(|s| s.ayahs.iter())
+                .find_map(|a| a.bismillah.clone())
+        });
+
+        let bismillah_words = self.bismillah_text.as_deref().map(split_words);
+
+        for surah in self.surahs.iter_mut() {
+            let Some(first_ayah) = surah.ayahs.first() else {
+                continue;
+            };
+
+            if first_ayah.bismillah.is_some() {
+                surah.bismillah_status = true;
+                surah.bismillah_as_first_ayah = false;
+            } else if bismillah_words.as_ref() == Some(&first_ayah.words) {
+                surah.bismillah_status = true;
+                surah.bismillah_as_first_ayah = true;
+            } else {
+                surah.bismillah_status = !has_markers && surah.number != 9;
+                surah.bismillah_as_first_ayah = false;
+            }
+        }
+    }
+}
+
+/// Creates the mushaf and all of its surahs, ayahs and words
+///
+/// Everything is inserted in a single transaction, so the mushaf is
+/// created completely or not at all
+pub fn import_mushaf(
+    conn: &mut PgConnection,
+    creator_user_id: i32,
+    new_mushaf: ImportMushaf,
+    mut parsed: ParsedMushaf,
+) -> Result<ImportReport, RouterError> {
+    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+    use crate::schema::quran_surahs::dsl::quran_surahs;
+    use crate::schema::quran_words::dsl::quran_words;
+
+    parsed.resolve_bismillah(new_mushaf.bismillah_text);
+
+    conn.transaction(|conn| {
+        let mushaf_exists: bool = diesel::select(exists(
+            quran_mushafs.filter(mushaf_short_name.eq(new_mushaf.short_name)),
+        ))
+        .get_result(conn)?;
+
+        if mushaf_exists {
+            return Err(RouterError::from_predefined("MUSHAF_ALREADY_EXISTS"));
+        }
+
+        let mushaf: QuranMushaf = NewQuranMushaf {
+            creator_user_id,
+            short_name: Some(new_mushaf.short_name),
+            name: new_mushaf.name,
+            source: new_mushaf.source,
+            bismillah_text: parsed.bismillah_text.clone(),
+        }
+        .insert_into(quran_mushafs)
+        .get_result(conn)?;
+
+        let mut report = ImportReport {
+            mushaf_uuid: mushaf.uuid,
+            surahs: 0,
+            ayahs: 0,
+            words: 0,
+        };
+
+        for surah in parsed.surahs {
+            let inserted_surah: QuranSurah = NewQuranSurah {
+                creator_user_id,
+                name: surah.name,
+                period: None,
+                number: surah.number,
+                bismillah_status: surah.bismillah_status,
+                bismillah_as_first_ayah: surah.bismillah_as_first_ayah,
+                mushaf_id: mushaf.id,
+                name_pronunciation: None,
+                name_translation_phrase: None,
+                name_transliteration: surah.name_transliteration,
+                revelation_order: None,
+                rukus: None,
+            }
+            .insert_into(quran_surahs)
+            .get_result(conn)?;
+
+            let inserted_ayahs: Vec<QuranAyah> = surah
+                .ayahs
+                .iter()
+                .map(|ayah| NewQuranAyah {
+                    creator_user_id,
+                    surah_id: inserted_surah.id,
+                    ayah_number: ayah.number,
+                    sajdah: ayah.sajdah.as_ref().map(|s| s.to_string()),
+                })
+                .collect::<Vec<NewQuranAyah>>()
+                .insert_into(quran_ayahs)
+                .get_results(conn)?;
+
+            let ayah_ids: BTreeMap<i32, i32> = inserted_ayahs
+                .iter()
+                .map(|a| (a.ayah_number, a.id))
+                .collect();
+
+            // The waqf signs are kept apart from the word text
+            let ayah_words: Vec<(i32, Vec<WaqfWord>)> = surah
+                .ayahs
+                .iter()
+                .map(|ayah| (ayah_ids[&ayah.number], separate_waqf(&ayah.words)))
+                .collect();
+
+            let words: Vec<NewQuranWord> = ayah_words
+                .iter()
+                .flat_map(|(ayah_id, words)| {
+                    words
+                        .iter()
+                        .enumerate()
+                        .map(move |(index, (word, waqf))| NewQuranWord {
+                            creator_user_id,
+                            ayah_id: *ayah_id,
+                            word,
+                            word_number: index as i32 + 1,
+                            waqf: waqf.map(|waqf| waqf.to_string()),
+                        })
+                })
+                .collect();
+
+            for chunk in words.chunks(MUSHAF_INSERT_CHUNK) {
+                diesel::insert_into(quran_words)
+                    .values(chunk)
+                    .execute(conn)?;
+            }
+
+            report.surahs += 1;
+            report.ayahs += inserted_ayahs.len();
+            report.words += words.len();
+        }
+
+        Ok(report)
+    })
+}
+
+/// Options of a command line tool, `--key value` pairs
+pub struct CliOptions<'a> {
+    options: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> CliOptions<'a> {
+    pub fn parse(args: &'a [String]) -> io::Result<Self> {
+        let mut options: BTreeMap<&str, &str> = BTreeMap::new();
+
+        for pair in args.chunks(2) {
+            match pair {
+                [key, value] if key.starts_with("--") => {
+                    options.insert(key.trim_start_matches("--"), value);
+                }
+
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("Invalid argument {:?}", pair),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { options })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&'a str> {
+        self.options.get(name).copied()
+    }
+
+    pub fn required(&self, name: &str) -> io::Result<&'a str> {
+        self.get(name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("--{} is required", name),
+            )
+        })
+    }
+
+    /// The user id of the `--account` option
+    pub fn user(&self, conn: &mut PgConnection) -> io::Result<i32> {
+        use crate::schema::app_accounts::dsl::{app_accounts, id as acc_id, username};
+        use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+
+        app_users
+            .inner_join(app_accounts.on(acc_id.eq(user_acc_id)))
+            .filter(username.eq(self.required("account")?))
+            .select(user_id)
+            .get_result(conn)
+            .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err.to_string()))
+    }
+}
+
+/// Runs the import from the command line
+///
+/// `nq-api import --format xml --file quran.xml --short-name hafs --account admin
+/// [--name ..] [--source ..] [--bismillah ..]`
+pub fn run_cli(pool: &DbPool, args: &[String]) -> io::Result<()> {
+    let options = CliOptions::parse(args)?;
+
+    let format = ImportFormat::try_from(options.required("format")?)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err.to_string()))?;
+    let content = std::fs::read_to_string(options.required("file")?)?;
+
+    let parsed = ParsedMushaf::parse(&format, &content)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    let mut conn = pool.get().unwrap();
+
+    let user = options.user(&mut conn)?;
+
+    let report = import_mushaf(
+        &mut conn,
+        user,
+        ImportMushaf {
+            short_name: options.required("short-name")?,
+            name: options.get("name"),
+            source: options.get("source"),
+            bismillah_text: options.get("bismillah").map(|b| b.to_string()),
+        },
+        parsed,
+    )
+    .map_err(|err| io::Error::other(err.to_string()))?;
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="utf-8" ?>
+<quran>
+    <sura index="1" name="الفاتحة">
+        <aya index="1" text="بسم الله الرحمن الرحيم" />
+        <aya index="2" text="الحمد لله رب العالمين" />
+    </sura>
+    <sura index="2" name="البقرة">
+        <aya index="1" text="الم" bismillah="بسم الله الرحمن الرحيم" />
+        <aya index="2" text="ذلك الكتاب لا ريب فيه" />
+    </sura>
+    <sajdas>
+        <sajda index="1" sura="2" aya="2" type="recommended" />
+    </sajdas>
+</quran>"#;
+
+    #[test]
+    fn test_parse_xml() {
+        let mut mushaf = parse_xml(XML).unwrap();
+        mushaf.resolve_bismillah(None);
+
+        assert_eq!(
+            mushaf.bismillah_text,
+            Some("بسم الله الرحمن الرحيم".to_string())
+        );
+        assert_eq!(mushaf.surahs.len(), 2);
+
+        let fatiha = &mushaf.surahs[0];
+        assert_eq!(fatiha.name, "الفاتحة");
+        assert!(fatiha.bismillah_status);
+        assert!(fatiha.bismillah_as_first_ayah);
+
+        let baqarah = &mushaf.surahs[1];
+        assert!(baqarah.bismillah_status);
+        assert!(!baqarah.bismillah_as_first_ayah);
+        assert_eq!(baqarah.ayahs[1].words.len(), 5);
+        assert_eq!(baqarah.ayahs[1].sajdah, Some(Sajdah::Mostahab));
+    }
+
+    #[test]
+    fn test_parse_xml_duplicate_ayah() {
+        let xml = r#"<quran><sura index="1"><aya index="1" text="a"/><aya index="1" text="b"/></sura></quran>"#;
+
+        assert_eq!(parse_xml(xml), Err(ImportError::DuplicateAyah(1, 1)));
+    }
+
+    #[test]
+    fn test_parse_text() {
+        let text =
+            "1|1|بسم الله الرحمن الرحيم\n\n9|1|براءة من الله\n9|2|فسيحوا في الأرض|vajib\n# comment";
+
+        let mut mushaf = parse_text(text).unwrap();
+        mushaf.resolve_bismillah(Some("بسم الله الرحمن الرحيم".to_string()));
+
+        assert_eq!(mushaf.surahs.len(), 2);
+        assert!(mushaf.surahs[0].bismillah_as_first_ayah);
+        assert!(!mushaf.surahs[1].bismillah_status);
+        assert_eq!(mushaf.surahs[1].ayahs[1].sajdah, Some(Sajdah::Vajib));
+    }
+
+    #[test]
+    fn test_parse_text_invalid_line() {
+        assert_eq!(parse_text("1|1"), Err(ImportError::InvalidLine(1)));
+        assert_eq!(parse_text("# only comments"), Err(ImportError::Empty));
+    }
+}
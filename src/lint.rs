@@ -0,0 +1,293 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::routers::quran::ayah::Sajdah;
+
+/// A word of the linted mushaf
+pub struct LintWord {
+    pub uuid: Uuid,
+    pub word: String,
+}
+
+/// An ayah of the linted mushaf, words are in order
+pub struct LintAyah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub sajdah: Option<String>,
+    pub words: Vec<LintWord>,
+}
+
+/// A surah of the linted mushaf, ayahs are ordered by number
+pub struct LintSurah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub bismillah_status: bool,
+    pub bismillah_as_first_ayah: bool,
+    pub ayahs: Vec<LintAyah>,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LintKind {
+    SurahNumberGap,
+    SurahNumberDuplicate,
+    AyahNumberGap,
+    AyahNumberDuplicate,
+    AyahWithoutWords,
+    EmptyWord,
+    NonArabicWord,
+    BismillahNotConsistent,
+    SajdahNotValid,
+}
+
+/// A single structural problem of the mushaf
+#[derive(Serialize, Debug, PartialEq)]
+pub struct LintIssue {
+    pub kind: LintKind,
+
+    /// Surah, ayah or word that has the problem
+    pub uuid: Uuid,
+
+    /// Like 2, 2:255 or 2:255:3
+    pub location: String,
+    pub detail: String,
+}
+
+/// Letters and marks of the arabic unicode blocks
+fn is_arabic(c: char) -> bool {
+    matches!(c,
+        '\u{0600}'..='\u{06FF}'
+        | '\u{0750}'..='\u{077F}'
+        | '\u{08A0}'..='\u{08FF}'
+        | '\u{FB50}'..='\u{FDFF}'
+        | '\u{FE70}'..='\u{FEFF}')
+}
+
+/// Checks that the numbers (in order) start from 1 without any
+/// gap or duplicate, returns the index and the detail of each problem
+fn number_issues(numbers: &[i32]) -> Vec<(usize, bool, String)> {
+    let mut issues = vec![];
+    let mut previous = 0;
+
+    for (index, number) in numbers.iter().enumerate() {
+        if *number == previous {
+            issues.push((index, true, format!("number {} is repeated", number)));
+        } else if *number != previous + 1 {
+            issues.push((
+                index,
+                false,
+                format!("expected number {}, found {}", previous + 1, number),
+            ));
+        }
+
+        previous = *number;
+    }
+
+    issues
+}
+
+/// Finds the structural problems of the mushaf surahs
+///
+/// The surahs must be ordered by number
+pub fn lint_mushaf(surahs: &[LintSurah], bismillah_text: Option<&str>) -> Vec<LintIssue> {
+    let mut issues = vec![];
+
+    let surah_numbers: Vec<i32> = surahs.iter().map(|surah| surah.number).collect();
+    for (index, duplicate, detail) in number_issues(&surah_numbers) {
+        issues.push(LintIssue {
+            kind: if duplicate {
+                LintKind::SurahNumberDuplicate
+            } else {
+                LintKind::SurahNumberGap
+            },
+            uuid: surahs[index].uuid,
+            location: surahs[index].number.to_string(),
+            detail,
+        });
+    }
+
+    for surah in surahs {
+        let ayah_numbers: Vec<i32> = surah.ayahs.iter().map(|ayah| ayah.number).collect();
+        for (index, duplicate, detail) in number_issues(&ayah_numbers) {
+            let ayah = &surah.ayahs[index];
+
+            issues.push(LintIssue {
+                kind: if duplicate {
+                    LintKind::AyahNumberDuplicate
+                } else {
+                    LintKind::AyahNumberGap
+                },
+                uuid: ayah.uuid,
+                location: format!("{}:{}", surah.number, ayah.number),
+                detail,
+            });
+        }
+
+        if surah.bismillah_as_first_ayah {
+            let first_ayah = surah.ayahs.first().map(|ayah| {
+                ayah.words
+                    .iter()
+                    .map(|word| word.word.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            });
+
+            let detail = if !surah.bismillah_status {
+                Some("bismillah is the first ayah but the surah has no bismillah".to_string())
+            } else {
+                match (bismillah_text, first_ayah) {
+                    (Some(bismillah), Some(first_ayah)) if bismillah != first_ayah => {
+                        Some("first ayah is not the mushaf bismillah".to_string())
+                    }
+
+                    _ => None,
+                }
+            };
+
+            if let Some(detail) = detail {
+                issues.push(LintIssue {
+                    kind: LintKind::BismillahNotConsistent,
+                    uuid: surah.uuid,
+                    location: surah.number.to_string(),
+                    detail,
+                });
+            }
+        }
+
+        for ayah in &surah.ayahs {
+            let location = format!("{}:{}", surah.number, ayah.number);
+
+            if let Some(ref sajdah) = ayah.sajdah {
+                if Sajdah::from_option_string(Some(sajdah.clone())).is_none() {
+                    issues.push(LintIssue {
+                        kind: LintKind::SajdahNotValid,
+                        uuid: ayah.uuid,
+                        location: location.clone(),
+                        detail: format!("sajdah {} is not valid", sajdah),
+                    });
+                }
+            }
+
+            if ayah.words.is_empty() {
+                issues.push(LintIssue {
+                    kind: LintKind::AyahWithoutWords,
+                    uuid: ayah.uuid,
+                    location: location.clone(),
+                    detail: "ayah does not have any word".to_string(),
+                });
+            }
+
+            for (index, word) in ayah.words.iter().enumerate() {
+                let location = format!("{}:{}", location, index + 1);
+
+                if word.word.trim().is_empty() {
+                    issues.push(LintIssue {
+                        kind: LintKind::EmptyWord,
+                        uuid: word.uuid,
+                        location,
+                        detail: "word is empty".to_string(),
+                    });
+
+                    continue;
+                }
+
+                let non_arabic: Vec<String> = word
+                    .word
+                    .chars()
+                    .filter(|c| !is_arabic(*c))
+                    .map(|c| format!("U+{:04X}", c as u32))
+                    .collect();
+
+                if !non_arabic.is_empty() {
+                    issues.push(LintIssue {
+                        kind: LintKind::NonArabicWord,
+                        uuid: word.uuid,
+                        location,
+                        detail: format!("word contains {}", non_arabic.join(", ")),
+                    });
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ayah(number: i32, sajdah: Option<&str>, words: &[&str]) -> LintAyah {
+        LintAyah {
+            uuid: Uuid::from_u128(number as u128),
+            number,
+            sajdah: sajdah.map(str::to_string),
+            words: words
+                .iter()
+                .map(|word| LintWord {
+                    uuid: Uuid::from_u128(0),
+                    word: word.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn surah(number: i32, bismillah: (bool, bool), ayahs: Vec<LintAyah>) -> LintSurah {
+        LintSurah {
+            uuid: Uuid::from_u128(number as u128),
+            number,
+            bismillah_status: bismillah.0,
+            bismillah_as_first_ayah: bismillah.1,
+            ayahs,
+        }
+    }
+
+    fn kinds(issues: Vec<LintIssue>) -> Vec<(LintKind, String)> {
+        issues
+            .into_iter()
+            .map(|issue| (issue.kind, issue.location))
+            .collect()
+    }
+
+    #[test]
+    fn test_number_issues() {
+        assert!(number_issues(&[1, 2, 3]).is_empty());
+        assert_eq!(
+            number_issues(&[1, 1, 4]),
+            vec![
+                (1, true, "number 1 is repeated".to_string()),
+                (2, false, "expected number 2, found 4".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_mushaf() {
+        let valid = vec![
+            surah(
+                1,
+                (true, true),
+                vec![ayah(1, None, &["بسم", "الله"]), ayah(2, None, &["الحمد"])],
+            ),
+            surah(2, (true, false), vec![ayah(1, Some("vajib"), &["الم"])]),
+        ];
+        assert!(lint_mushaf(&valid, Some("بسم الله")).is_empty());
+
+        let broken = vec![
+            surah(1, (false, true), vec![ayah(1, Some("x"), &["abc", " "])]),
+            surah(3, (true, false), vec![ayah(2, None, &[])]),
+        ];
+        assert_eq!(
+            kinds(lint_mushaf(&broken, None)),
+            vec![
+                (LintKind::SurahNumberGap, "3".to_string()),
+                (LintKind::BismillahNotConsistent, "1".to_string()),
+                (LintKind::SajdahNotValid, "1:1".to_string()),
+                (LintKind::NonArabicWord, "1:1:1".to_string()),
+                (LintKind::EmptyWord, "1:1:2".to_string()),
+                (LintKind::AyahNumberGap, "3:2".to_string()),
+                (LintKind::AyahWithoutWords, "3:2".to_string()),
+            ]
+        );
+    }
+}
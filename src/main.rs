@@ -26,16 +26,25 @@ use std::{env, io};
 use token_checker::UserIdFromToken;
 
 mod authz;
+mod buckwalter;
+mod checksum;
 mod datetime;
 mod email;
 mod error;
+mod export;
 mod filter;
+mod import;
+mod lint;
 pub mod models;
 mod models_filter;
+mod morphology;
+mod reference;
 mod routers;
 mod schema;
 mod select_model;
+mod tajweed;
 mod token_checker;
+mod transliteration;
 mod validate;
 
 mod difference;
@@ -51,7 +60,11 @@ use routers::permission::{
 };
 use routers::phrase::{add_phrase, delete_phrase, edit_phrase, phrase_list, view_phrase};
 use routers::profile::{profile_edit, profile_view};
-use routers::quran::{ayah::*, mushaf::*, surah::*, word::*};
+use routers::quran::{ayah::*, division::*, mushaf::*, surah::*, word::*};
+use routers::recitation::*;
+use routers::search::{quran_search, translation_search, word_concordance};
+use routers::tafsir::*;
+use routers::topic::*;
 use routers::translation::*;
 use routers::user::{add_user, delete_user, edit_user, users_list, view_user};
 
@@ -138,6 +151,18 @@ async fn main() -> std::io::Result<()> {
 
     run_migrations(&mut pool.get().unwrap()).unwrap();
 
+    // `nq-api import ...` imports a mushaf file, `nq-api morphology ...`
+    // and `nq-api tajweed ...` import the word morphology and tajweed,
+    // they exit without starting the server
+    let args: Vec<String> = env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("import") => return import::run_cli(&pool, &args[2..]),
+        Some("morphology") => return morphology::run_cli(&pool, &args[2..]),
+        Some("tajweed") => return tajweed::run_cli(&pool, &args[2..]),
+
+        _ => {}
+    }
+
     let mailer = create_emailer();
 
     let user_id_from_token = UserIdFromToken::new(pool.clone());
@@ -219,10 +244,135 @@ async fn main() -> std::io::Result<()> {
                         ),
                     ),
             )
+            .service(
+                web::scope("/tafsir")
+                    .route("", web::get().to(tafsir_list::tafsir_list))
+                    .route("/{tafsir_uuid}", web::get().to(tafsir_view::tafsir_view))
+                    .service(
+                        web::resource("")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(tafsir_add::tafsir_add)),
+                    )
+                    .service(
+                        web::resource("/{tafsir_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(tafsir_edit::tafsir_edit))
+                            .route(web::delete().to(tafsir_delete::tafsir_delete)),
+                    )
+                    .service(
+                        web::resource("/{tafsir_uuid}/entry")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(tafsir_entry_add::tafsir_entry_add)),
+                    )
+                    .service(
+                        web::resource("/{tafsir_uuid}/entry/{entry_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(tafsir_entry_edit::tafsir_entry_edit))
+                            .route(web::delete().to(tafsir_entry_delete::tafsir_entry_delete)),
+                    ),
+            )
+            .service(
+                web::scope("/topic")
+                    .route("", web::get().to(topic_list::topic_list))
+                    .route("/{topic_uuid}", web::get().to(topic_view::topic_view))
+                    .service(
+                        web::resource("")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(topic_add::topic_add)),
+                    )
+                    .service(
+                        web::resource("/{topic_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(topic_edit::topic_edit))
+                            .route(web::delete().to(topic_delete::topic_delete)),
+                    )
+                    .service(
+                        web::resource("/{topic_uuid}/ayah")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(topic_ayah_add::topic_ayah_add)),
+                    )
+                    .service(
+                        web::resource("/{topic_uuid}/ayah/{range_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::delete().to(topic_ayah_delete::topic_ayah_delete)),
+                    ),
+            )
+            .service(
+                web::scope("/reciter")
+                    .route("", web::get().to(reciter_list::reciter_list))
+                    .route("/{reciter_uuid}", web::get().to(reciter_view::reciter_view))
+                    .service(
+                        web::resource("")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(reciter_add::reciter_add)),
+                    )
+                    .service(
+                        web::resource("/{reciter_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(reciter_edit::reciter_edit))
+                            .route(web::delete().to(reciter_delete::reciter_delete)),
+                    ),
+            )
+            .service(
+                web::scope("/recitation")
+                    .route("", web::get().to(recitation_list::recitation_list))
+                    .route(
+                        "/{recitation_uuid}",
+                        web::get().to(recitation_view::recitation_view),
+                    )
+                    .route(
+                        "/{recitation_uuid}/ayah",
+                        web::get().to(recitation_ayah_view::recitation_ayah_view),
+                    )
+                    .service(
+                        web::resource("")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(recitation_add::recitation_add)),
+                    )
+                    .service(
+                        web::resource("/{recitation_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(recitation_edit::recitation_edit))
+                            .route(web::delete().to(recitation_delete::recitation_delete)),
+                    )
+                    .service(
+                        web::resource("/{recitation_uuid}/ayah")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(recitation_ayah_modify::recitation_ayah_modify))
+                            .route(
+                                web::delete().to(recitation_ayah_delete::recitation_ayah_delete),
+                            ),
+                    ),
+            )
             .service(
                 web::scope("/ayah")
                     .route("", web::get().to(ayah_list::ayah_list))
+                    .route(
+                        "/ref/{reference}",
+                        web::get().to(ayah_reference::ayah_reference),
+                    )
                     .route("/{ayah_uuid}", web::get().to(ayah_view::ayah_view))
+                    .route(
+                        "/{ayah_uuid}/tafsir",
+                        web::get().to(ayah_tafsir::ayah_tafsir),
+                    )
+                    .route(
+                        "/{ayah_uuid}/context",
+                        web::get().to(ayah_context::ayah_context),
+                    )
                     .service(
                         web::resource("")
                             .wrap(AuthZ::new(auth_z_controller.clone()))
@@ -235,11 +385,21 @@ async fn main() -> std::io::Result<()> {
                             .wrap(TokenAuth::new(user_id_from_token.clone(), true))
                             .route(web::post().to(ayah_edit::ayah_edit))
                             .route(web::delete().to(ayah_delete::ayah_delete)),
+                    )
+                    .service(
+                        web::resource("/{ayah_uuid}/words")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(ayah_words_modify::ayah_words_modify)),
                     ),
             )
             .service(
                 web::scope("/word")
                     .route("/{word_uuid}", web::get().to(word_view::word_view))
+                    .route(
+                        "/{word_uuid}/translation",
+                        web::get().to(word_translation_view::word_translation_view),
+                    )
                     .service(
                         web::resource("")
                             .wrap(AuthZ::new(auth_z_controller.clone()))
@@ -252,18 +412,97 @@ async fn main() -> std::io::Result<()> {
                             .wrap(TokenAuth::new(user_id_from_token.clone(), false))
                             .route(web::post().to(word_edit::word_edit))
                             .route(web::delete().to(word_delete::word_delete)),
+                    )
+                    .service(
+                        web::resource("/{word_uuid}/translation")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(word_translation_modify::word_translation_modify))
+                            .route(
+                                web::delete().to(word_translation_delete::word_translation_delete),
+                            ),
                     ),
             )
             .service(
                 web::scope("/mushaf")
                     .route("", web::get().to(mushaf_list::mushaf_list))
+                    .route("/compare", web::get().to(mushaf_compare::mushaf_compare))
                     .route("/{mushaf_uuid}", web::get().to(mushaf_view::mushaf_view))
+                    .route(
+                        "/{mushaf_uuid}/export",
+                        web::get().to(mushaf_export::mushaf_export),
+                    )
+                    .route(
+                        "/{mushaf_uuid}/page/{page}",
+                        web::get().to(mushaf_page::mushaf_page),
+                    )
+                    .route(
+                        "/{mushaf_uuid}/sajdahs",
+                        web::get().to(mushaf_sajdahs::mushaf_sajdahs),
+                    )
                     .service(
                         web::resource("")
                             .wrap(AuthZ::new(auth_z_controller.clone()))
                             .wrap(TokenAuth::new(user_id_from_token.clone(), true))
                             .route(web::post().to(mushaf_add::mushaf_add)),
                     )
+                    .service(
+                        web::resource("/import")
+                            .app_data(web::PayloadConfig::new(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_import::mushaf_import)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/layout")
+                            .app_data(web::JsonConfig::default().limit(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_layout::mushaf_layout)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/morphology")
+                            .app_data(web::PayloadConfig::new(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_morphology::mushaf_morphology)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/lint")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::get().to(mushaf_lint::mushaf_lint)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/tajweed")
+                            .app_data(web::PayloadConfig::new(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_tajweed::mushaf_tajweed)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/word_translations")
+                            .app_data(web::JsonConfig::default().limit(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(
+                                web::post().to(mushaf_word_translations::mushaf_word_translations),
+                            ),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/word_scripts")
+                            .app_data(web::JsonConfig::default().limit(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_word_scripts::mushaf_word_scripts)),
+                    )
+                    .service(
+                        web::resource("/{mushaf_uuid}/markers")
+                            .app_data(web::JsonConfig::default().limit(MUSHAF_IMPORT_LIMIT))
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(mushaf_markers::mushaf_markers)),
+                    )
                     .service(
                         web::resource("/{mushaf_uuid}")
                             .wrap(AuthZ::new(auth_z_controller.clone()))
@@ -272,6 +511,41 @@ async fn main() -> std::io::Result<()> {
                             .route(web::delete().to(mushaf_delete::mushaf_delete)),
                     ),
             )
+            .service(
+                web::scope("/division")
+                    .route("", web::get().to(division_list::division_list))
+                    .route(
+                        "/{division_uuid}",
+                        web::get().to(division_view::division_view),
+                    )
+                    .service(
+                        web::resource("")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), true))
+                            .route(web::post().to(division_add::division_add)),
+                    )
+                    .service(
+                        web::resource("/{division_uuid}")
+                            .wrap(AuthZ::new(auth_z_controller.clone()))
+                            .wrap(TokenAuth::new(user_id_from_token.clone(), false))
+                            .route(web::post().to(division_edit::division_edit))
+                            .route(web::delete().to(division_delete::division_delete)),
+                    ),
+            )
+            .route("/juz/{number}", web::get().to(division_view::juz_view))
+            .route("/hizb/{number}", web::get().to(division_view::hizb_view))
+            .service(
+                web::scope("/search")
+                    .route("", web::get().to(quran_search::quran_search))
+                    .route(
+                        "/translation",
+                        web::get().to(translation_search::translation_search),
+                    )
+                    .route(
+                        "/concordance",
+                        web::get().to(word_concordance::word_concordance),
+                    ),
+            )
             .service(
                 web::scope("/user")
                     .wrap(AuthZ::new(auth_z_controller.clone()))
@@ -262,6 +262,14 @@ pub struct NewQuranAyah {
     pub sajdah: Option<String>,
 }
 
+#[derive(Insertable)]
+#[diesel(table_name = quran_ayah_markers)]
+pub struct NewQuranAyahMarker<'a> {
+    pub creator_user_id: i32,
+    pub ayah_id: i32,
+    pub marker: &'a str,
+}
+
 #[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
 #[diesel(belongs_to(QuranAyah, foreign_key = ayah_id))]
 #[diesel(table_name = quran_words)]
@@ -280,6 +288,16 @@ pub struct QuranWord {
     pub created_at: NaiveDateTime,
     #[serde(skip_serializing)]
     pub updated_at: NaiveDateTime,
+
+    /// Generated by the database, see `quran_normalize_arabic`
+    #[serde(skip_serializing)]
+    pub normalized_word: String,
+
+    /// Position of the word in the ayah, starting from 1
+    pub word_number: i32,
+
+    /// Waqf sign after the word, the stored word text does not have it
+    pub waqf: Option<String>,
 }
 
 #[derive(Insertable)]
@@ -288,6 +306,229 @@ pub struct NewQuranWord<'a> {
     pub creator_user_id: i32,
     pub ayah_id: i32,
     pub word: &'a str,
+    pub word_number: i32,
+    pub waqf: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_layouts)]
+pub struct NewQuranWordLayout {
+    pub creator_user_id: i32,
+    pub word_id: i32,
+    pub page: i32,
+    pub line: i32,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranWord, foreign_key = word_id))]
+#[diesel(table_name = quran_word_morphologies)]
+pub struct QuranWordMorphology {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub word_id: i32,
+
+    pub root: Option<String>,
+    pub lemma: Option<String>,
+    pub part_of_speech: String,
+    pub person: Option<String>,
+    pub gender: Option<String>,
+    pub grammatical_number: Option<String>,
+    pub grammatical_case: Option<String>,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_morphologies)]
+pub struct NewQuranWordMorphology<'a> {
+    pub creator_user_id: i32,
+    pub word_id: i32,
+    pub root: Option<&'a str>,
+    pub lemma: Option<&'a str>,
+    pub part_of_speech: &'a str,
+    pub person: Option<&'a str>,
+    pub gender: Option<&'a str>,
+    pub grammatical_number: Option<&'a str>,
+    pub grammatical_case: Option<&'a str>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_segments)]
+pub struct NewQuranWordSegment<'a> {
+    pub creator_user_id: i32,
+    pub morphology_id: i32,
+    pub segment_number: i32,
+    pub form: &'a str,
+    pub tag: &'a str,
+    pub features: &'a str,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_tajweeds)]
+pub struct NewQuranWordTajweed<'a> {
+    pub creator_user_id: i32,
+    pub word_id: i32,
+    pub rule: &'a str,
+    pub start_index: i32,
+    pub end_index: i32,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranWord, foreign_key = word_id))]
+#[diesel(table_name = quran_word_translations)]
+pub struct QuranWordTranslation {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub word_id: i32,
+
+    pub language: String,
+    pub text: String,
+    pub transliteration: Option<String>,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_translations)]
+pub struct NewQuranWordTranslation<'a> {
+    pub creator_user_id: i32,
+    pub word_id: i32,
+    pub language: &'a str,
+    pub text: &'a str,
+    pub transliteration: Option<&'a str>,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranWord, foreign_key = word_id))]
+#[diesel(table_name = quran_word_scripts)]
+pub struct QuranWordScript {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub word_id: i32,
+
+    pub script: String,
+    pub text: String,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_word_scripts)]
+pub struct NewQuranWordScript<'a> {
+    pub creator_user_id: i32,
+    pub word_id: i32,
+    pub script: &'a str,
+    pub text: &'a str,
+}
+
+#[derive(Clone, Selectable, Identifiable, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(table_name = quran_reciters)]
+pub struct QuranReciter {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub account_id: Option<i32>,
+
+    pub name: String,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_reciters)]
+pub struct NewQuranReciter<'a> {
+    pub creator_user_id: i32,
+    pub account_id: Option<i32>,
+    pub name: &'a str,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranReciter, foreign_key = reciter_id))]
+#[diesel(table_name = quran_recitations)]
+pub struct QuranRecitation {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub reciter_id: i32,
+
+    #[serde(skip_serializing)]
+    pub mushaf_id: i32,
+
+    pub style: Option<String>,
+    pub source: Option<String>,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_recitations)]
+pub struct NewQuranRecitation<'a> {
+    pub creator_user_id: i32,
+    pub reciter_id: i32,
+    pub mushaf_id: i32,
+    pub style: Option<&'a str>,
+    pub source: Option<&'a str>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_recitation_ayahs)]
+pub struct NewQuranRecitationAyah<'a> {
+    pub creator_user_id: i32,
+    pub recitation_id: i32,
+    pub ayah_id: i32,
+    pub url: &'a str,
+    pub duration: i32,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_recitation_words)]
+pub struct NewQuranRecitationWord {
+    pub creator_user_id: i32,
+    pub recitation_ayah_id: i32,
+    pub word_id: i32,
+    pub start_time: i32,
+    pub end_time: i32,
 }
 
 #[derive(Deserialize, Serialize, Clone, Validate, Identifiable, Queryable, Selectable, Debug)]
@@ -316,6 +557,9 @@ pub struct QuranSurah {
     pub created_at: NaiveDateTime,
     #[serde(skip_serializing)]
     pub updated_at: NaiveDateTime,
+
+    pub revelation_order: Option<i32>,
+    pub rukus: Option<i32>,
 }
 
 #[derive(Insertable)]
@@ -331,6 +575,8 @@ pub struct NewQuranSurah {
     pub name_pronunciation: Option<String>,
     pub name_translation_phrase: Option<String>,
     pub name_transliteration: Option<String>,
+    pub revelation_order: Option<i32>,
+    pub rukus: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Clone, Validate, Identifiable, Queryable, Selectable, Debug)]
@@ -364,6 +610,44 @@ pub struct NewQuranMushaf<'a> {
     pub bismillah_text: Option<String>,
 }
 
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, Debug, Serialize)]
+#[diesel(belongs_to(QuranMushaf, foreign_key = mushaf_id))]
+#[diesel(table_name = quran_divisions)]
+pub struct QuranDivision {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub mushaf_id: i32,
+
+    pub division_type: String,
+    pub number: i32,
+
+    #[serde(skip_serializing)]
+    pub start_ayah_id: i32,
+    #[serde(skip_serializing)]
+    pub end_ayah_id: i32,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_divisions)]
+pub struct NewQuranDivision {
+    pub creator_user_id: i32,
+    pub mushaf_id: i32,
+    pub division_type: String,
+    pub number: i32,
+    pub start_ayah_id: i32,
+    pub end_ayah_id: i32,
+}
+
 #[derive(Deserialize, Serialize, Clone, Validate, Identifiable, Queryable, Debug, Selectable)]
 #[diesel(table_name = app_permissions)]
 #[diesel(belongs_to(Account))]
@@ -535,6 +819,148 @@ pub struct NewTranslationText<'a> {
     pub text: &'a String,
 }
 
+#[derive(Clone, Selectable, Identifiable, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(table_name = quran_tafsirs)]
+pub struct QuranTafsir {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub mushaf_id: i32,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub author_account_id: i32,
+
+    pub name: String,
+    pub language: String,
+    pub source: Option<String>,
+
+    /// tafsir content status
+    pub approved: bool,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_tafsirs)]
+pub struct NewQuranTafsir<'a> {
+    pub creator_user_id: i32,
+    pub mushaf_id: i32,
+    pub author_account_id: i32,
+    pub name: &'a str,
+    pub language: &'a str,
+    pub source: Option<&'a str>,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranTafsir, foreign_key = tafsir_id))]
+#[diesel(table_name = quran_tafsir_entries)]
+pub struct QuranTafsirEntry {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub tafsir_id: i32,
+
+    #[serde(skip_serializing)]
+    pub start_ayah_id: i32,
+
+    #[serde(skip_serializing)]
+    pub end_ayah_id: i32,
+
+    pub text: String,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_tafsir_entries)]
+pub struct NewQuranTafsirEntry<'a> {
+    pub creator_user_id: i32,
+    pub tafsir_id: i32,
+    pub start_ayah_id: i32,
+    pub end_ayah_id: i32,
+    pub text: &'a str,
+}
+
+#[derive(Clone, Selectable, Identifiable, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(table_name = quran_topics)]
+pub struct QuranTopic {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub parent_id: Option<i32>,
+
+    pub name_phrase: String,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_topics)]
+pub struct NewQuranTopic<'a> {
+    pub creator_user_id: i32,
+    pub parent_id: Option<i32>,
+    pub name_phrase: &'a str,
+}
+
+#[derive(Clone, Selectable, Identifiable, Associations, Queryable, PartialEq, Debug, Serialize)]
+#[diesel(belongs_to(QuranTopic, foreign_key = topic_id))]
+#[diesel(table_name = quran_topic_ayahs)]
+pub struct QuranTopicAyah {
+    #[serde(skip_serializing)]
+    pub id: i32,
+    pub uuid: Uuid,
+
+    #[serde(skip_serializing)]
+    pub creator_user_id: i32,
+
+    #[serde(skip_serializing)]
+    pub topic_id: i32,
+
+    #[serde(skip_serializing)]
+    pub start_ayah_id: i32,
+
+    #[serde(skip_serializing)]
+    pub end_ayah_id: i32,
+
+    #[serde(skip_serializing)]
+    pub created_at: NaiveDateTime,
+    #[serde(skip_serializing)]
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = quran_topic_ayahs)]
+pub struct NewQuranTopicAyah {
+    pub creator_user_id: i32,
+    pub topic_id: i32,
+    pub start_ayah_id: i32,
+    pub end_ayah_id: i32,
+}
+
 #[derive(Deserialize, Serialize, Clone, Validate, Identifiable, Queryable, Debug, Selectable)]
 #[diesel(table_name = app_error_logs)]
 pub struct ErrorLog {
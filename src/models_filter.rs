@@ -49,6 +49,15 @@ impl Filter for QuranSurah {
                     Order::Desc => quran_surahs.order(number.desc()).internal_into_boxed(),
                 }),
 
+                "revelationOrder" => Ok(match filters.order().unwrap_or_default() {
+                    Order::Asc => quran_surahs
+                        .order(revelation_order.asc())
+                        .internal_into_boxed(),
+                    Order::Desc => quran_surahs
+                        .order(revelation_order.desc())
+                        .internal_into_boxed(),
+                }),
+
                 "createTime" => Ok(match filters.order().unwrap_or_default() {
                     Order::Asc => quran_surahs.order(created_at.asc()).internal_into_boxed(),
                     Order::Desc => quran_surahs.order(created_at.desc()).internal_into_boxed(),
@@ -0,0 +1,398 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+use std::io;
+
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use serde::Serialize;
+
+use crate::buckwalter;
+use crate::error::RouterError;
+use crate::import::CliOptions;
+use crate::models::{NewQuranWordMorphology, NewQuranWordSegment};
+use crate::routers::quran::{word_positions, WordPosition};
+use crate::DbPool;
+
+/// Maximum rows sent in a single insert statement
+///
+/// Postgres accepts at most 65535 bind params per statement, a morphology
+/// row has 9 columns so it needs a smaller chunk than MUSHAF_INSERT_CHUNK
+/// (9 * 5_000 params)
+const MORPHOLOGY_INSERT_CHUNK: usize = 5_000;
+
+/// Errors that can happen while parsing the morphology file
+#[derive(Debug, PartialEq)]
+pub enum MorphologyError {
+    InvalidLine(usize),
+    InvalidLocation(usize),
+    DuplicateSegment(usize),
+    Empty,
+}
+
+impl Display for MorphologyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLine(line) => write!(
+                f,
+                "line {} is not in LOCATION FORM TAG FEATURES format",
+                line
+            ),
+            Self::InvalidLocation(line) => write!(
+                f,
+                "location of line {} is not in (surah:ayah:word:segment) format",
+                line
+            ),
+            Self::DuplicateSegment(line) => write!(f, "segment of line {} is defined twice", line),
+            Self::Empty => write!(f, "file does not contain any segment"),
+        }
+    }
+}
+
+impl From<MorphologyError> for RouterError {
+    fn from(value: MorphologyError) -> Self {
+        Self::from_predefined_with_detail("MORPHOLOGY_NOT_VALID", &value.to_string())
+    }
+}
+
+/// A prefix, stem or suffix of the word
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSegment {
+    /// The arabic form of the segment
+    pub form: String,
+    pub tag: String,
+
+    /// The corpus features, as they are in the file
+    pub features: String,
+}
+
+/// Morphology of a single word
+///
+/// Root, lemma and the grammatical features come from the stem
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedWordMorphology {
+    pub position: WordPosition,
+    pub root: Option<String>,
+    pub lemma: Option<String>,
+    pub part_of_speech: String,
+    pub person: Option<String>,
+    pub gender: Option<String>,
+    pub number: Option<String>,
+    pub case: Option<String>,
+    pub segments: Vec<ParsedSegment>,
+}
+
+/// What the morphology import has created
+#[derive(Serialize, Debug)]
+pub struct MorphologyReport {
+    pub words: usize,
+    pub segments: usize,
+}
+
+fn parse_location(value: &str, line: usize) -> Result<(WordPosition, u32), MorphologyError> {
+    let numbers = value
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .split(':')
+        .map(|number| number.parse::<u32>())
+        .collect::<Result<Vec<u32>, _>>()
+        .map_err(|_| MorphologyError::InvalidLocation(line))?;
+
+    match numbers.as_slice() {
+        [surah, ayah, word, segment] if numbers.iter().all(|n| *n > 0) => {
+            Ok(((*surah as i32, *ayah as i32, *word), *segment))
+        }
+
+        _ => Err(MorphologyError::InvalidLocation(line)),
+    }
+}
+
+/// Person, gender and number feature, like `3MS`, `MP` or `F`
+fn parse_pgn(feature: &str) -> Option<(Option<String>, Option<String>, Option<String>)> {
+    let mut chars = feature.chars().peekable();
+
+    let person = chars.next_if(|c| matches!(c, '1' | '2' | '3'));
+    let gender = chars.next_if(|c| matches!(c, 'M' | 'F'));
+    let number = chars.next_if(|c| matches!(c, 'S' | 'D' | 'P'));
+
+    if chars.next().is_some() || (person.is_none() && gender.is_none() && number.is_none()) {
+        return None;
+    }
+
+    Some((
+        person.map(String::from),
+        gender.map(String::from),
+        number.map(String::from),
+    ))
+}
+
+impl ParsedWordMorphology {
+    fn from_segments(position: WordPosition, segments: Vec<ParsedSegment>) -> Self {
+        // Every word of the corpus has a stem, but don't depend on it
+        let stem = segments
+            .iter()
+            .find(|segment| segment.features.starts_with("STEM"))
+            .or(segments.last())
+            .cloned()
+            .unwrap();
+
+        let mut word = Self {
+            position,
+            root: None,
+            lemma: None,
+            part_of_speech: stem.tag,
+            person: None,
+            gender: None,
+            number: None,
+            case: None,
+            segments,
+        };
+
+        for feature in stem.features.split('|') {
+            if let Some(root) = feature.strip_prefix("ROOT:") {
+                word.root = Some(buckwalter::to_arabic(root));
+            } else if let Some(lemma) = feature.strip_prefix("LEM:") {
+                word.lemma = Some(buckwalter::to_arabic(lemma));
+            } else if matches!(feature, "NOM" | "ACC" | "GEN") {
+                word.case = Some(feature.to_string());
+            } else if let Some((person, gender, number)) = parse_pgn(feature) {
+                word.person = person;
+                word.gender = gender;
+                word.number = number;
+            }
+        }
+
+        word
+    }
+}
+
+/// Parse the Quranic Arabic Corpus morphology file
+///
+/// Each line is a segment, `(surah:ayah:word:segment) FORM TAG FEATURES`
+/// separated by tabs, the form is in Buckwalter transliteration.
+/// Empty lines, `#` comments and the header are skipped
+pub fn parse_corpus(content: &str) -> Result<Vec<ParsedWordMorphology>, MorphologyError> {
+    let mut words: BTreeMap<WordPosition, BTreeMap<u32, ParsedSegment>> = BTreeMap::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim_end();
+
+        if line.trim().is_empty() || line.starts_with('#') || line.starts_with("LOCATION") {
+            continue;
+        }
+
+        let [location, form, tag, features] = line.split('\t').collect::<Vec<&str>>()[..] else {
+            return Err(MorphologyError::InvalidLine(line_number));
+        };
+
+        let (position, segment_number) = parse_location(location, line_number)?;
+
+        let segment = ParsedSegment {
+            form: buckwalter::to_arabic(form),
+            tag: tag.to_string(),
+            features: features.to_string(),
+        };
+
+        if words
+            .entry(position)
+            .or_default()
+            .insert(segment_number, segment)
+            .is_some()
+        {
+            return Err(MorphologyError::DuplicateSegment(line_number));
+        }
+    }
+
+    if words.is_empty() {
+        return Err(MorphologyError::Empty);
+    }
+
+    Ok(words
+        .into_iter()
+        .map(|(position, segments)| {
+            ParsedWordMorphology::from_segments(position, segments.into_values().collect())
+        })
+        .collect())
+}
+
+/// Replaces the morphology of the mushaf words
+///
+/// Words are found by their position, every word of the file must
+/// be in the mushaf
+pub fn import_morphology(
+    conn: &mut PgConnection,
+    creator_user_id: i32,
+    mushaf: i32,
+    words: Vec<ParsedWordMorphology>,
+) -> Result<MorphologyReport, RouterError> {
+    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, quran_surahs};
+    use crate::schema::quran_word_morphologies::dsl::{
+        id as m_id, quran_word_morphologies, word_id as morphology_word_id,
+    };
+    use crate::schema::quran_word_segments::dsl::quran_word_segments;
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words};
+
+    let positions = word_positions(conn, mushaf)?;
+
+    let mut new_morphologies: Vec<NewQuranWordMorphology> = Vec::with_capacity(words.len());
+
+    for word in &words {
+        let Some(id) = positions.get(&word.position) else {
+            let (surah, ayah, position) = word.position;
+
+            return Err(RouterError::from_predefined_with_detail(
+                "MORPHOLOGY_NOT_VALID",
+                &format!("word {}:{}:{} is not in the mushaf", surah, ayah, position),
+            ));
+        };
+
+        new_morphologies.push(NewQuranWordMorphology {
+            creator_user_id,
+            word_id: *id,
+            root: word.root.as_deref(),
+            lemma: word.lemma.as_deref(),
+            part_of_speech: &word.part_of_speech,
+            person: word.person.as_deref(),
+            gender: word.gender.as_deref(),
+            grammatical_number: word.number.as_deref(),
+            grammatical_case: word.case.as_deref(),
+        });
+    }
+
+    conn.transaction(|conn| {
+        let mushaf_word_ids = quran_words
+            .inner_join(quran_ayahs.inner_join(quran_surahs))
+            .filter(surah_mushaf_id.eq(mushaf))
+            .select(word_id);
+
+        // Segments are removed with their morphology
+        diesel::delete(quran_word_morphologies.filter(morphology_word_id.eq_any(mushaf_word_ids)))
+            .execute(conn)?;
+
+        // word id -> morphology id
+        let mut inserted: HashMap<i32, i32> = HashMap::with_capacity(new_morphologies.len());
+
+        for chunk in new_morphologies.chunks(MORPHOLOGY_INSERT_CHUNK) {
+            let ids: Vec<(i32, i32)> = chunk
+                .insert_into(quran_word_morphologies)
+                .returning((morphology_word_id, m_id))
+                .get_results(conn)?;
+
+            inserted.extend(ids);
+        }
+
+        let new_segments: Vec<NewQuranWordSegment> = words
+            .iter()
+            .zip(new_morphologies.iter())
+            .flat_map(|(word, morphology)| {
+                let morphology_id = inserted[&morphology.word_id];
+
+                word.segments
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, segment)| NewQuranWordSegment {
+                        creator_user_id,
+                        morphology_id,
+                        segment_number: index as i32 + 1,
+                        form: &segment.form,
+                        tag: &segment.tag,
+                        features: &segment.features,
+                    })
+            })
+            .collect();
+
+        for chunk in new_segments.chunks(MORPHOLOGY_INSERT_CHUNK) {
+            chunk.insert_into(quran_word_segments).execute(conn)?;
+        }
+
+        Ok(MorphologyReport {
+            words: new_morphologies.len(),
+            segments: new_segments.len(),
+        })
+    })
+}
+
+/// Runs the morphology import from the command line
+///
+/// `nq-api morphology --file quranic-corpus-morphology.txt --mushaf hafs --account admin`
+pub fn run_cli(pool: &DbPool, args: &[String]) -> io::Result<()> {
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, short_name};
+
+    let options = CliOptions::parse(args)?;
+
+    let content = std::fs::read_to_string(options.required("file")?)?;
+
+    let words = parse_corpus(&content)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    let mut conn = pool.get().unwrap();
+
+    let user = options.user(&mut conn)?;
+
+    let mushaf: i32 = quran_mushafs
+        .filter(short_name.eq(options.required("mushaf")?))
+        .select(mushaf_id)
+        .get_result(&mut conn)
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err.to_string()))?;
+
+    let report = import_morphology(&mut conn, user, mushaf, words)
+        .map_err(|err| io::Error::other(err.to_string()))?;
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CORPUS: &str = "# Quranic Arabic Corpus
+LOCATION\tFORM\tTAG\tFEATURES
+(1:1:1:1)\tbi\tP\tPREFIX|bi+
+(1:1:1:2)\tsomi\tN\tSTEM|POS:N|LEM:{som|ROOT:smw|M|GEN
+(1:2:3:1)\tqaAla\tV\tSTEM|POS:V|PERF|LEM:qaAla|ROOT:qwl|3MS
+(1:2:3:2)\thum\tPRON\tSUFFIX|PRON:3MP
+";
+
+    #[test]
+    fn test_parse_corpus() {
+        let words = parse_corpus(CORPUS).unwrap();
+
+        assert_eq!(words.len(), 2);
+
+        let ism = &words[0];
+        assert_eq!(ism.position, (1, 1, 1));
+        assert_eq!(ism.part_of_speech, "N");
+        assert_eq!(ism.root.as_deref(), Some("سمو"));
+        assert_eq!(ism.lemma.as_deref(), Some("ٱسْم"));
+        assert_eq!(ism.gender.as_deref(), Some("M"));
+        assert_eq!(ism.case.as_deref(), Some("GEN"));
+        assert_eq!(ism.segments.len(), 2);
+        assert_eq!(ism.segments[0].form, "بِ");
+
+        let qala = &words[1];
+        assert_eq!(qala.part_of_speech, "V");
+        assert_eq!(qala.person.as_deref(), Some("3"));
+        assert_eq!(qala.number.as_deref(), Some("S"));
+        assert_eq!(qala.case, None);
+    }
+
+    #[test]
+    fn test_parse_corpus_errors() {
+        assert_eq!(
+            parse_corpus("(1:1:1)\tbi\tP\tPREFIX|bi+"),
+            Err(MorphologyError::InvalidLocation(1))
+        );
+        assert_eq!(
+            parse_corpus("(1:1:1:1)\tbi\tP"),
+            Err(MorphologyError::InvalidLine(1))
+        );
+        assert_eq!(
+            parse_corpus("(1:1:1:1)\tbi\tP\tPREFIX|bi+\n(1:1:1:1)\tbi\tP\tPREFIX|bi+"),
+            Err(MorphologyError::DuplicateSegment(2))
+        );
+        assert_eq!(parse_corpus("# nothing"), Err(MorphologyError::Empty));
+    }
+}
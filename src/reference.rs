@@ -0,0 +1,255 @@
+use std::fmt::Display;
+
+/// The surah part of a reference, by number or by name
+#[derive(Debug, Clone, PartialEq)]
+pub enum SurahKey {
+    Number(i32),
+
+    /// Transliterated or arabic name, like `Al-Baqarah` or `البقرة`
+    Name(String),
+}
+
+/// A single point of the reference
+///
+/// When the ayah is None the point is the first (for start) or
+/// the last (for end) ayah of the surah
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerseKey {
+    pub surah: SurahKey,
+    pub ayah: Option<i32>,
+}
+
+/// Parsed ayah reference, start and end are both included
+///
+/// Examples: `2:255`, `2:255-257`, `2:286-3:5`, `2`, `Al-Baqarah 255`, `Al-Baqarah 255-257`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub start: VerseKey,
+    pub end: VerseKey,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReferenceError {
+    Empty,
+
+    /// The part of the reference that can't be parsed
+    Invalid(String),
+}
+
+impl Display for ReferenceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "reference is empty"),
+            Self::Invalid(part) => write!(f, "'{}' is not a valid reference", part),
+        }
+    }
+}
+
+fn parse_number(value: &str) -> Result<i32, ReferenceError> {
+    match value.trim().parse::<i32>() {
+        Ok(number) if number > 0 => Ok(number),
+
+        _ => Err(ReferenceError::Invalid(value.to_string())),
+    }
+}
+
+/// Parses `surah:ayah` or `ayah` (from the given surah)
+fn parse_point(value: &str, surah: &SurahKey) -> Result<VerseKey, ReferenceError> {
+    match value.split_once(':') {
+        Some((surah_number, ayah)) => Ok(VerseKey {
+            surah: SurahKey::Number(parse_number(surah_number)?),
+            ayah: Some(parse_number(ayah)?),
+        }),
+
+        None => Ok(VerseKey {
+            surah: surah.clone(),
+            ayah: Some(parse_number(value)?),
+        }),
+    }
+}
+
+/// Parses the ayah part after the surah, `255`, `255-257` or `286-3:5`
+fn parse_ayahs(value: &str, surah: SurahKey) -> Result<Reference, ReferenceError> {
+    let (start, end) = match value.split_once('-') {
+        Some((start, end)) => (start, Some(end)),
+        None => (value, None),
+    };
+
+    let start = parse_point(start, &surah)?;
+    let end = match end {
+        Some(end) => parse_point(end, &start.surah)?,
+        None => start.clone(),
+    };
+
+    Ok(Reference { start, end })
+}
+
+impl TryFrom<&str> for Reference {
+    type Error = ReferenceError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+
+        if value.is_empty() {
+            return Err(ReferenceError::Empty);
+        }
+
+        // Numeric reference, 2 or 2:255 or 2:255-257 or 2:286-3:5
+        if value.starts_with(|c: char| c.is_ascii_digit()) {
+            let Some((surah, ayahs)) = value.split_once(':') else {
+                let surah = SurahKey::Number(parse_number(value)?);
+
+                return Ok(Self {
+                    start: VerseKey {
+                        surah: surah.clone(),
+                        ayah: None,
+                    },
+                    end: VerseKey { surah, ayah: None },
+                });
+            };
+
+            return parse_ayahs(ayahs, SurahKey::Number(parse_number(surah)?));
+        }
+
+        // Named reference, the ayahs (if any) are after the last space
+        // Al-Baqarah or Al-Baqarah 255 or Al-Baqarah 255-257
+        match value.rsplit_once(char::is_whitespace) {
+            Some((name, ayahs)) if ayahs.starts_with(|c: char| c.is_ascii_digit()) => {
+                parse_ayahs(ayahs, SurahKey::Name(name.trim().to_string()))
+            }
+
+            _ => {
+                let surah = SurahKey::Name(value.to_string());
+
+                Ok(Self {
+                    start: VerseKey {
+                        surah: surah.clone(),
+                        ayah: None,
+                    },
+                    end: VerseKey { surah, ayah: None },
+                })
+            }
+        }
+    }
+}
+
+/// The article of the transliterated names with its assimilated
+/// forms (`An-Nas`, `Ash-Shams`), longest first
+const SURAH_NAME_ARTICLES: &[&str] = &[
+    "ash", "ath", "adh", "al", "an", "ar", "as", "at", "ad", "az",
+];
+
+/// Normalizes a surah name for comparing,
+/// `Al-Baqarah`, `al baqarah` and `Baqarah` are all `baqarah`
+///
+/// The transliterated article is only removed when it's separated
+/// from the name, so `Alaq` and `Al-'Alaq` are both `alaq`
+pub fn normalize_surah_name(name: &str) -> String {
+    let name = name.trim().to_lowercase();
+
+    let without_article = SURAH_NAME_ARTICLES
+        .iter()
+        .find_map(|article| {
+            name.strip_prefix(article)
+                .filter(|rest| rest.starts_with(['-', ' ', '\'', '\u{2019}']))
+        })
+        .or_else(|| name.strip_prefix("ال"))
+        .filter(|rest| rest.chars().any(|c| c.is_alphanumeric()))
+        .unwrap_or(&name);
+
+    without_article
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(surah: i32, ayah: i32) -> VerseKey {
+        VerseKey {
+            surah: SurahKey::Number(surah),
+            ayah: Some(ayah),
+        }
+    }
+
+    #[test]
+    fn test_numeric_reference() {
+        assert_eq!(
+            Reference::try_from("2:255").unwrap(),
+            Reference {
+                start: key(2, 255),
+                end: key(2, 255)
+            }
+        );
+
+        assert_eq!(
+            Reference::try_from("2:255-257").unwrap(),
+            Reference {
+                start: key(2, 255),
+                end: key(2, 257)
+            }
+        );
+
+        assert_eq!(
+            Reference::try_from(" 2:286-3:5 ").unwrap(),
+            Reference {
+                start: key(2, 286),
+                end: key(3, 5)
+            }
+        );
+
+        let whole_surah = Reference::try_from("114").unwrap();
+        assert_eq!(whole_surah.start.surah, SurahKey::Number(114));
+        assert_eq!(whole_surah.start.ayah, None);
+        assert_eq!(whole_surah.end.ayah, None);
+    }
+
+    #[test]
+    fn test_named_reference() {
+        let reference = Reference::try_from("Al-Baqarah 255-257").unwrap();
+        let name = SurahKey::Name("Al-Baqarah".to_string());
+
+        assert_eq!(reference.start.surah, name);
+        assert_eq!(reference.start.ayah, Some(255));
+        assert_eq!(reference.end.surah, name);
+        assert_eq!(reference.end.ayah, Some(257));
+
+        let reference = Reference::try_from("Al-Fatihah").unwrap();
+        assert_eq!(
+            reference.start.surah,
+            SurahKey::Name("Al-Fatihah".to_string())
+        );
+        assert_eq!(reference.start.ayah, None);
+    }
+
+    #[test]
+    fn test_invalid_reference() {
+        assert_eq!(Reference::try_from("  "), Err(ReferenceError::Empty));
+        assert!(Reference::try_from("2:").is_err());
+        assert!(Reference::try_from("2:0").is_err());
+        assert!(Reference::try_from("2:a-5").is_err());
+        assert!(Reference::try_from("2:255-").is_err());
+    }
+
+    #[test]
+    fn test_normalize_surah_name() {
+        assert_eq!(normalize_surah_name("Al-Baqarah"), "baqarah");
+        assert_eq!(normalize_surah_name("al baqarah"), "baqarah");
+        assert_eq!(normalize_surah_name("Baqarah"), "baqarah");
+        assert_eq!(normalize_surah_name("البقرة"), "بقرة");
+    }
+
+    #[test]
+    fn test_normalize_surah_name_article() {
+        assert_eq!(normalize_surah_name("Alaq"), "alaq");
+        assert_eq!(normalize_surah_name("Al-'Alaq"), "alaq");
+        assert_eq!(normalize_surah_name("An-Nas"), normalize_surah_name("Nas"));
+        assert_eq!(normalize_surah_name("Ar-Rahman"), "rahman");
+        assert_eq!(normalize_surah_name("Ash-Shams"), "shams");
+        assert_eq!(normalize_surah_name("As-Saffat"), "saffat");
+        assert_eq!(normalize_surah_name("at tur"), "tur");
+        assert_eq!(normalize_surah_name("Al-"), "al");
+    }
+}
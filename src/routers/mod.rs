@@ -5,6 +5,10 @@ pub mod permission;
 pub mod phrase;
 pub mod profile;
 pub mod quran;
+pub mod recitation;
+pub mod search;
+pub mod tafsir;
+pub mod topic;
 pub mod translation;
 pub mod user;
 
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use crate::error::RouterError;
 use crate::models::{NewQuranAyah, NewQuranWord, QuranAyah};
+use crate::routers::quran::word::{check_waqf_word, separate_waqf};
 use crate::DbPool;
 use actix_web::web;
 use diesel::prelude::*;
@@ -15,6 +16,10 @@ pub struct AyahWithText {
     pub surah_uuid: String,
     pub sajdah: Option<Sajdah>,
     pub text: String,
+
+    /// Position of the ayah in the surah, the ayahs after it are shifted.
+    /// When not given the ayah is added to the end of the surah
+    pub ayah_number: Option<i32>,
 }
 
 /// Add's a new ayah
@@ -24,7 +29,7 @@ pub async fn ayah_add(
     data: web::ReqData<u32>,
 ) -> Result<&'static str, RouterError> {
     use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
-    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, surah_id as ayah_surah_id};
     use crate::schema::quran_surahs::dsl::{id as surah_id, quran_surahs, uuid as surah_uuid};
     use crate::schema::quran_words::dsl::quran_words;
 
@@ -46,37 +51,75 @@ pub async fn ayah_add(
             .select(surah_id)
             .get_result(&mut conn)?;
 
-        // Calculate amount of ayahs in surah
-        let latest_ayah_number: i64 = quran_ayahs
-            .inner_join(quran_surahs)
-            .filter(surah_id.eq(target_surah))
-            .count()
-            .get_result(&mut conn)?;
+        conn.transaction(|conn| {
+            // Calculate amount of ayahs in surah
+            let ayahs_count: i64 = quran_ayahs
+                .filter(ayah_surah_id.eq(target_surah))
+                .count()
+                .get_result(conn)?;
+
+            let number = match new_ayah.ayah_number {
+                Some(number) if number >= 1 && number as i64 <= ayahs_count + 1 => number,
+
+                Some(number) => {
+                    return Err(RouterError::from_predefined_with_detail(
+                        "AYAH_NUMBER_NOT_VALID",
+                        &format!(
+                            "ayah number {} is not between 1 and {}",
+                            number,
+                            ayahs_count + 1
+                        ),
+                    ))
+                }
+
+                None => ayahs_count as i32 + 1,
+            };
 
-        // Insert new ayah
-        let ayah: QuranAyah = NewQuranAyah {
-            surah_id: target_surah,
-            sajdah: new_ayah.sajdah.map(|sajdah| sajdah.to_string()),
-            ayah_number: (latest_ayah_number + 1) as i32,
-            creator_user_id: user,
-        }
-        .insert_into(quran_ayahs)
-        .get_result(&mut conn)?;
-
-        // Split the ayah text by space and insert them as quran_word
-        let words: Vec<NewQuranWord> = new_ayah
-            .text
-            .split(' ')
-            .map(|w| NewQuranWord {
+            // Shift the ayahs after the new one
+            diesel::update(quran_ayahs)
+                .filter(ayah_surah_id.eq(target_surah))
+                .filter(ayah_number.ge(number))
+                .set(ayah_number.eq(ayah_number + 1))
+                .execute(conn)?;
+
+            // Insert new ayah
+            let ayah: QuranAyah = NewQuranAyah {
+                surah_id: target_surah,
+                sajdah: new_ayah.sajdah.map(|sajdah| sajdah.to_string()),
+                ayah_number: number,
                 creator_user_id: user,
-                word: w,
-                ayah_id: ayah.id,
-            })
-            .collect();
+            }
+            .insert_into(quran_ayahs)
+            .get_result(conn)?;
+
+            // Split the ayah text by space and insert them as quran_word
+            let split_words = separate_waqf(new_ayah.text.split(' '));
+
+            for (index, word) in split_words.iter().enumerate() {
+                check_waqf_word(word).map_err(|err| {
+                    RouterError::from_predefined_with_detail(
+                        "AYAH_WORDS_NOT_VALID",
+                        &format!("word {}: {}", index + 1, err),
+                    )
+                })?;
+            }
+
+            let words: Vec<NewQuranWord> = split_words
+                .iter()
+                .enumerate()
+                .map(|(index, (w, waqf))| NewQuranWord {
+                    creator_user_id: user,
+                    word: w,
+                    ayah_id: ayah.id,
+                    word_number: index as i32 + 1,
+                    waqf: waqf.map(|waqf| waqf.to_string()),
+                })
+                .collect();
 
-        words.insert_into(quran_words).execute(&mut conn)?;
+            words.insert_into(quran_words).execute(conn)?;
 
-        Ok("Added")
+            Ok("Added")
+        })
     })
     .await
     .unwrap()
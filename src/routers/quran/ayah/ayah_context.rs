@@ -0,0 +1,49 @@
+use super::{AyahContextQuery, AyahContextResponse, AYAH_CONTEXT_LIMIT};
+use crate::error::RouterError;
+use crate::routers::quran::{ayahs_in_range, nearby_ayahs, AyahPosition};
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+/// Returns the ayah with the ayahs around it, the counts
+/// are limited to AYAH_CONTEXT_LIMIT
+pub async fn ayah_context(
+    path: web::Path<Uuid>,
+    web::Query(query): web::Query<AyahContextQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<AyahContextResponse>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    let requested_ayah_uuid = path.into_inner();
+
+    let before = query.before.unwrap_or(2).min(AYAH_CONTEXT_LIMIT) as i64;
+    let after = query.after.unwrap_or(2).min(AYAH_CONTEXT_LIMIT) as i64;
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (mushaf, position): (i32, AyahPosition) = quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(ayah_uuid.eq(requested_ayah_uuid))
+            .select((mushaf_id, (surah_number, ayah_number)))
+            .get_result(&mut conn)?;
+
+        // The farthest ayahs are the start and the end of the range
+        let start = nearby_ayahs(&mut conn, mushaf, position, false, before)?
+            .pop()
+            .map_or(position, |(_, start)| start);
+
+        let end = nearby_ayahs(&mut conn, mushaf, position, true, after)?
+            .pop()
+            .map_or(position, |(_, end)| end);
+
+        Ok(web::Json(AyahContextResponse {
+            ayah_uuid: requested_ayah_uuid,
+            surahs: ayahs_in_range(&mut conn, mushaf, start, end, query.format)?,
+        }))
+    })
+    .await
+    .unwrap()
+}
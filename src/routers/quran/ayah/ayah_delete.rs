@@ -4,21 +4,32 @@ use ::uuid::Uuid;
 use actix_web::web;
 use diesel::prelude::*;
 
-/// Delete's a single ayah
+/// Delete's a single ayah, the ayahs after it are renumbered
 pub async fn ayah_delete(
     path: web::Path<Uuid>,
     pool: web::Data<DbPool>,
 ) -> Result<&'static str, RouterError> {
-    use crate::schema::quran_ayahs::dsl::{quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, surah_id, uuid as ayah_uuid};
 
     let target_ayah_uuid = path.into_inner();
 
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
-        diesel::delete(quran_ayahs.filter(ayah_uuid.eq(target_ayah_uuid))).execute(&mut conn)?;
+        conn.transaction(|conn| {
+            let (surah, number): (i32, i32) =
+                diesel::delete(quran_ayahs.filter(ayah_uuid.eq(target_ayah_uuid)))
+                    .returning((surah_id, ayah_number))
+                    .get_result(conn)?;
 
-        Ok("Deleted")
+            diesel::update(quran_ayahs)
+                .filter(surah_id.eq(surah))
+                .filter(ayah_number.gt(number))
+                .set(ayah_number.eq(ayah_number - 1))
+                .execute(conn)?;
+
+            Ok("Deleted")
+        })
     })
     .await
     .unwrap()
@@ -7,13 +7,16 @@ use uuid::Uuid;
 use super::SimpleAyah;
 
 /// Update's single ayah
+///
+/// When the number changes the ayah is moved, and the
+/// other ayahs of the surah are renumbered
 pub async fn ayah_edit(
     path: web::Path<Uuid>,
     new_ayah: web::Json<SimpleAyah>,
     pool: web::Data<DbPool>,
 ) -> Result<&'static str, RouterError> {
     use crate::schema::quran_ayahs::dsl::{
-        ayah_number, quran_ayahs, sajdah as ayah_sajdah, uuid as ayah_uuid,
+        ayah_number, id as ayah_id, quran_ayahs, sajdah as ayah_sajdah, surah_id, uuid as ayah_uuid,
     };
 
     let new_ayah = new_ayah.into_inner();
@@ -24,14 +27,55 @@ pub async fn ayah_edit(
 
         let new_sajdah = new_ayah.sajdah.map(|sajdah| sajdah.to_string());
 
-        diesel::update(quran_ayahs.filter(ayah_uuid.eq(target_ayah_uuid)))
-            .set((
-                ayah_number.eq(new_ayah.ayah_number),
-                ayah_sajdah.eq(new_sajdah),
-            ))
-            .execute(&mut conn)?;
+        conn.transaction(|conn| {
+            // Numbers are unique per surah, they are checked
+            // when the transaction is committed
+            diesel::sql_query("SET CONSTRAINTS quran_ayahs_ayah_number DEFERRED").execute(conn)?;
 
-        Ok("Edited")
+            let (target_id, surah, old_number): (i32, i32, i32) = quran_ayahs
+                .filter(ayah_uuid.eq(target_ayah_uuid))
+                .select((ayah_id, surah_id, ayah_number))
+                .get_result(conn)?;
+
+            let ayahs_count: i64 = quran_ayahs
+                .filter(surah_id.eq(surah))
+                .count()
+                .get_result(conn)?;
+
+            if new_ayah.ayah_number < 1 || new_ayah.ayah_number as i64 > ayahs_count {
+                return Err(RouterError::from_predefined_with_detail(
+                    "AYAH_NUMBER_NOT_VALID",
+                    &format!(
+                        "ayah number {} is not between 1 and {}",
+                        new_ayah.ayah_number, ayahs_count
+                    ),
+                ));
+            }
+
+            // Close the gap of the old position and open the new one
+            diesel::update(quran_ayahs)
+                .filter(surah_id.eq(surah))
+                .filter(ayah_id.ne(target_id))
+                .filter(ayah_number.gt(old_number))
+                .set(ayah_number.eq(ayah_number - 1))
+                .execute(conn)?;
+
+            diesel::update(quran_ayahs)
+                .filter(surah_id.eq(surah))
+                .filter(ayah_id.ne(target_id))
+                .filter(ayah_number.ge(new_ayah.ayah_number))
+                .set(ayah_number.eq(ayah_number + 1))
+                .execute(conn)?;
+
+            diesel::update(quran_ayahs.filter(ayah_id.eq(target_id)))
+                .set((
+                    ayah_number.eq(new_ayah.ayah_number),
+                    ayah_sajdah.eq(new_sajdah),
+                ))
+                .execute(conn)?;
+
+            Ok("Edited")
+        })
     })
     .await
     .unwrap()
@@ -2,6 +2,8 @@ use crate::error::{RouterError, RouterErrorDetailBuilder};
 use crate::filter::Filter;
 use crate::models::QuranAyah;
 use crate::routers::multip;
+use crate::routers::quran::ayah::marked_ayahs;
+use crate::routers::quran::word::{render_words, WaqfMark};
 use crate::{
     routers::quran::surah::{AyahTy, Format, SimpleAyah},
     DbPool,
@@ -17,9 +19,12 @@ pub async fn ayah_list(
     web::Query(query): web::Query<AyahListQuery>,
     req: HttpRequest,
 ) -> Result<web::Json<Vec<AyahTy>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::id as ayah_id;
     use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
     use crate::schema::quran_surahs::dsl::quran_surahs;
-    use crate::schema::quran_words::dsl::{quran_words, word as q_word};
+    use crate::schema::quran_words::dsl::{
+        id as word_id, quran_words, waqf, word as q_word, word_number,
+    };
 
     let pool = pool.into_inner();
 
@@ -28,17 +33,36 @@ pub async fn ayah_list(
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
-        let filtered_ayahs = match QuranAyah::filter(Box::from(query.clone())) {
+        let mut filtered_ayahs = match QuranAyah::filter(Box::from(query.clone())) {
             Ok(filtered) => filtered,
             Err(err) => return Err(err.log_to_db(pool, error_detail)),
         };
 
-        let ayahs = filtered_ayahs
+        if let Some(marker) = query.marker {
+            filtered_ayahs = filtered_ayahs.filter(ayah_id.eq_any(marked_ayahs(marker)));
+        }
+
+        let mut ayahs = filtered_ayahs
             .left_outer_join(quran_surahs.left_outer_join(quran_mushafs))
             .inner_join(quran_words)
             .filter(mushaf_short_name.eq(query.mushaf))
-            .select((QuranAyah::as_select(), q_word))
-            .get_results::<(QuranAyah, String)>(&mut conn)?;
+            .then_order_by(word_number.asc())
+            .select((QuranAyah::as_select(), (word_id, q_word, waqf)))
+            .get_results::<(QuranAyah, (i32, String, Option<String>))>(&mut conn)?;
+
+        render_words(
+            &mut conn,
+            query.script,
+            true,
+            ayahs.iter_mut().map(|(_, (id, word, word_waqf))| {
+                (*id, word, WaqfMark::from_option_string(word_waqf.clone()))
+            }),
+        )?;
+
+        let ayahs = ayahs
+            .into_iter()
+            .map(|(ayah, (_, word, _))| (ayah, word))
+            .collect();
 
         let ayahs_as_map = multip(ayahs, |a| SimpleAyah {
             number: a.ayah_number as u32,
@@ -52,8 +76,19 @@ pub async fn ayah_list(
                 Some(Format::Text) | None => AyahTy::Text(crate::AyahWithText {
                     ayah,
                     text: words.join(" "),
+                    transliteration: None,
+                    audio: None,
+                }),
+                Some(Format::Word) => AyahTy::Words(crate::AyahWithWords {
+                    ayah,
+                    words,
+                    morphology: None,
+                    translations: None,
+                    tajweed: None,
+                    waqf: None,
+                    transliteration: None,
+                    audio: None,
                 }),
-                Some(Format::Word) => AyahTy::Words(crate::AyahWithWords { ayah, words }),
             })
             .collect::<Vec<AyahTy>>();
 
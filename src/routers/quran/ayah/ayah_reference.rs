@@ -0,0 +1,145 @@
+use super::{AyahReferenceQuery, AyahReferenceResponse};
+use crate::error::RouterError;
+use crate::reference::{normalize_surah_name, Reference, SurahKey, VerseKey};
+use crate::routers::quran::{ayahs_in_range, AyahPosition};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+/// A surah of the mushaf (id, number, names)
+type ReferenceSurah = (i32, i32, String, Option<String>, Option<String>);
+
+fn not_found(detail: String) -> RouterError {
+    RouterError::from_predefined_with_detail("AYAH_REFERENCE_NOT_FOUND", &detail)
+}
+
+/// Finds the surah of the reference in the mushaf surahs
+fn find_surah<'a>(
+    surahs: &'a [ReferenceSurah],
+    key: &SurahKey,
+) -> Result<&'a ReferenceSurah, RouterError> {
+    let found = match key {
+        SurahKey::Number(number) => surahs.iter().find(|surah| surah.1 == *number),
+
+        SurahKey::Name(name) => {
+            let name = normalize_surah_name(name);
+
+            surahs
+                .iter()
+                .find(|(_, _, arabic, pronunciation, transliteration)| {
+                    [
+                        Some(arabic),
+                        pronunciation.as_ref(),
+                        transliteration.as_ref(),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .any(|surah_name| normalize_surah_name(surah_name) == name)
+                })
+        }
+    };
+
+    found.ok_or_else(|| {
+        not_found(match key {
+            SurahKey::Number(number) => format!("surah {} is not in the mushaf", number),
+            SurahKey::Name(name) => format!("surah {} is not in the mushaf", name),
+        })
+    })
+}
+
+/// Resolves ayah references like 2:255, 2:255-257, 2:286-3:5 or Al-Baqarah 255
+pub async fn ayah_reference(
+    path: web::Path<String>,
+    web::Query(query): web::Query<AyahReferenceQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<AyahReferenceResponse>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, surah_id as ayah_surah_id};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, short_name};
+    use crate::schema::quran_surahs::dsl::{
+        id as surah_id, mushaf_id as surah_mushaf_id, name, name_pronunciation,
+        name_transliteration, number as surah_number, quran_surahs,
+    };
+
+    let reference = Reference::try_from(path.as_str()).map_err(|err| {
+        RouterError::from_predefined_with_detail("AYAH_REFERENCE_NOT_VALID", &err.to_string())
+    })?;
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mushaf: i32 = quran_mushafs
+            .filter(short_name.eq(&query.mushaf))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let surahs: Vec<ReferenceSurah> = quran_surahs
+            .filter(surah_mushaf_id.eq(mushaf))
+            .select((
+                surah_id,
+                surah_number,
+                name,
+                name_pronunciation,
+                name_transliteration,
+            ))
+            .get_results(&mut conn)?;
+
+        // Finds the position of the reference point, the ayah
+        // must be in the surah
+        let mut position = |key: &VerseKey, is_end: bool| -> Result<AyahPosition, RouterError> {
+            let surah = find_surah(&surahs, &key.surah)?;
+
+            let ayah = match key.ayah {
+                Some(ayah) => quran_ayahs
+                    .filter(ayah_surah_id.eq(surah.0))
+                    .filter(ayah_number.eq(ayah))
+                    .select(ayah_number)
+                    .first::<i32>(&mut conn)
+                    .optional()?,
+
+                None if is_end => quran_ayahs
+                    .filter(ayah_surah_id.eq(surah.0))
+                    .order(ayah_number.desc())
+                    .select(ayah_number)
+                    .first::<i32>(&mut conn)
+                    .optional()?,
+
+                None => Some(1),
+            };
+
+            match ayah {
+                Some(ayah) => Ok((surah.1, ayah)),
+
+                None => Err(not_found(format!(
+                    "ayah {} is not in the surah {}",
+                    key.ayah.unwrap_or_default(),
+                    surah.1
+                ))),
+            }
+        };
+
+        let start = position(&reference.start, false)?;
+        let end = position(&reference.end, true)?;
+
+        if start > end {
+            return Err(RouterError::from_predefined_with_detail(
+                "AYAH_REFERENCE_NOT_VALID",
+                "start ayah is after the end ayah",
+            ));
+        }
+
+        let resolved = if start == end {
+            format!("{}:{}", start.0, start.1)
+        } else if start.0 == end.0 {
+            format!("{}:{}-{}", start.0, start.1, end.1)
+        } else {
+            format!("{}:{}-{}:{}", start.0, start.1, end.0, end.1)
+        };
+
+        Ok(web::Json(AyahReferenceResponse {
+            reference: resolved,
+            surahs: ayahs_in_range(&mut conn, mushaf, start, end, query.format)?,
+        }))
+    })
+    .await
+    .unwrap()
+}
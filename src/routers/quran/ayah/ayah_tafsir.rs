@@ -0,0 +1,104 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+use crate::error::RouterError;
+use crate::models::{QuranTafsir, QuranTafsirEntry};
+use crate::routers::tafsir::{entry_items, AyahTafsir, AyahTafsirQuery, TafsirItem};
+use crate::routers::translation::{translator_data, TranslatorData};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Returns the tafsir entries that cover the ayah
+pub async fn ayah_tafsir(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<AyahTafsirQuery>,
+) -> Result<web::Json<Vec<AyahTafsir>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, surah_id, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_tafsir_entries::dsl::{
+        end_ayah_id, quran_tafsir_entries, start_ayah_id,
+    };
+    use crate::schema::quran_tafsirs::dsl::{
+        language as tafsir_language, name as tafsir_name, quran_tafsirs, uuid as tafsir_uuid,
+    };
+
+    let requested_ayah = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (surah, number): (i32, i32) = quran_ayahs
+            .filter(ayah_uuid.eq(requested_ayah))
+            .select((surah_id, ayah_number))
+            .get_result(&mut conn)?;
+
+        // The entry starts at or before the ayah and
+        // ends at or after it, in the same surah
+        let mut entries_query = quran_tafsir_entries
+            .inner_join(quran_tafsirs)
+            .filter(
+                start_ayah_id.eq_any(
+                    quran_ayahs
+                        .filter(surah_id.eq(surah))
+                        .filter(ayah_number.le(number))
+                        .select(ayah_id),
+                ),
+            )
+            .filter(
+                end_ayah_id.eq_any(
+                    quran_ayahs
+                        .filter(surah_id.eq(surah))
+                        .filter(ayah_number.ge(number))
+                        .select(ayah_id),
+                ),
+            )
+            .order(tafsir_name.asc())
+            .select((QuranTafsir::as_select(), QuranTafsirEntry::as_select()))
+            .into_boxed();
+
+        if let Some(lang) = query.language {
+            entries_query = entries_query.filter(tafsir_language.eq(lang));
+        }
+
+        if let Some(tafsir) = query.tafsir {
+            entries_query = entries_query.filter(tafsir_uuid.eq(tafsir));
+        }
+
+        let (tafsirs, entries): (Vec<QuranTafsir>, Vec<QuranTafsirEntry>) = entries_query
+            .get_results::<(QuranTafsir, QuranTafsirEntry)>(&mut conn)?
+            .into_iter()
+            .unzip();
+
+        let entries = entry_items(&mut conn, entries)?;
+
+        // A tafsir can have more than one entry that covers the ayah
+        let mut authors: HashMap<i32, TranslatorData> = HashMap::new();
+
+        for tafsir in &tafsirs {
+            if let Entry::Vacant(author) = authors.entry(tafsir.id) {
+                author.insert(translator_data(&mut conn, tafsir.author_account_id)?);
+            }
+        }
+
+        Ok(web::Json(
+            tafsirs
+                .into_iter()
+                .zip(entries)
+                .map(|(tafsir, entry)| {
+                    let author = authors[&tafsir.id].clone();
+
+                    AyahTafsir {
+                        tafsir: TafsirItem::with_author(tafsir, author),
+                        entry,
+                    }
+                })
+                .collect(),
+        ))
+    })
+    .await
+    .unwrap()
+}
@@ -1,6 +1,9 @@
-use super::SimpleWord;
+use super::{AyahNeighbour, AyahViewQuery, SimpleWord};
 use crate::error::RouterError;
 use crate::models::{QuranAyah, QuranWord};
+use crate::routers::quran::nearby_ayahs;
+use crate::routers::quran::word::{render_words, word_morphologies, word_translations, WaqfMark};
+use crate::routers::recitation::ayah_audios;
 use crate::{AyahWithContent, DbPool, Sajdah};
 use ::uuid::Uuid;
 use actix_web::web;
@@ -9,16 +12,19 @@ use diesel::prelude::*;
 /// Return's a single ayah
 pub async fn ayah_view(
     path: web::Path<Uuid>,
+    query: web::Query<AyahViewQuery>,
     pool: web::Data<DbPool>,
 ) -> Result<web::Json<AyahWithContent>, RouterError> {
     use crate::schema::quran_ayahs::dsl::{quran_ayahs, uuid as ayah_uuid};
     use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
     use crate::schema::quran_surahs::dsl::{
-        id as surah_id, mushaf_id as surah_mushaf_id, quran_surahs, uuid as surah_uuid,
+        id as surah_id, mushaf_id as surah_mushaf_id, number as surah_number, quran_surahs,
+        uuid as surah_uuid,
     };
-    use crate::schema::quran_words::dsl::{ayah_id, id as word_id, quran_words};
+    use crate::schema::quran_words::dsl::{ayah_id, quran_words, word_number};
 
     let requested_ayah_uuid = path.into_inner();
+    let query = query.into_inner();
 
     web::block(move || {
         let mut conn = pool.get().unwrap();
@@ -28,9 +34,9 @@ pub async fn ayah_view(
             .filter(ayah_uuid.eq(requested_ayah_uuid))
             .get_result(&mut conn)?;
 
-        let surah: (Uuid, i32) = quran_surahs
+        let surah: (Uuid, i32, i32) = quran_surahs
             .filter(surah_id.eq(quran_ayah.surah_id))
-            .select((surah_uuid, surah_mushaf_id))
+            .select((surah_uuid, surah_mushaf_id, surah_number))
             .get_result(&mut conn)?;
 
         let mushaf: Uuid = quran_mushafs
@@ -38,19 +44,52 @@ pub async fn ayah_view(
             .select(mushaf_uuid)
             .get_result(&mut conn)?;
 
-        let words: Vec<QuranWord> = quran_words
+        let mut words: Vec<QuranWord> = quran_words
             .filter(ayah_id.eq(quran_ayah.id))
-            .order(word_id.asc())
+            .order(word_number.asc())
             .get_results(&mut conn)?;
 
+        render_words(
+            &mut conn,
+            query.script,
+            true,
+            words.iter_mut().map(|word| {
+                let mark = WaqfMark::from_option_string(word.waqf.clone());
+
+                (word.id, &mut word.word, mark)
+            }),
+        )?;
+
+        let word_ids: Vec<i32> = words.iter().map(|word| word.id).collect();
+
+        let mut morphologies = word_morphologies(&mut conn, &word_ids)?;
+
+        let mut translations = match query.word_lang {
+            Some(ref lang) => word_translations(&mut conn, &word_ids, lang)?,
+            None => Default::default(),
+        };
+
         let words_simple: Vec<SimpleWord> = words
             .into_iter()
             .map(|word| SimpleWord {
+                morphology: morphologies.remove(&word.id),
+                translation: translations.remove(&word.id),
                 word: word.word,
                 uuid: word.uuid,
             })
             .collect();
 
+        let audio = match query.recitation {
+            Some(recitation) => {
+                ayah_audios(&mut conn, recitation, &[quran_ayah.id])?.remove(&quran_ayah.uuid)
+            }
+            None => None,
+        };
+
+        let position = (surah.2, quran_ayah.ayah_number);
+        let prev = nearby_ayahs(&mut conn, surah.1, position, false, 1)?.pop();
+        let next = nearby_ayahs(&mut conn, surah.1, position, true, 1)?.pop();
+
         let text = words_simple
             .clone()
             .into_iter()
@@ -65,7 +104,11 @@ pub async fn ayah_view(
             sajdah: Sajdah::from_option_string(quran_ayah.sajdah),
             ayah_number: quran_ayah.ayah_number,
             words: words_simple,
+            transliteration: query.translit.map(|kind| kind.transliterate(&text)),
             text,
+            audio,
+            prev: prev.map(AyahNeighbour::from),
+            next: next.map(AyahNeighbour::from),
         }))
     })
     .await
@@ -0,0 +1,131 @@
+use super::{check_ayah_words, AyahWords};
+use crate::error::RouterError;
+use crate::models::NewQuranWord;
+use crate::routers::quran::word::{check_waqf_word, split_waqf};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// Replaces the word list of the ayah
+///
+/// Kept words are moved to their new position (and keep their
+/// morphology, translations and timings), new words are added and
+/// the rest are deleted, all in one transaction
+pub async fn ayah_words_modify(
+    path: web::Path<Uuid>,
+    new_words: web::Json<AyahWords>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, quran_words, uuid as word_uuid, waqf as word_waqf,
+        word as word_content, word_number,
+    };
+
+    let new_words = new_words.into_inner();
+    let target_ayah_uuid = path.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let ayah: i32 = quran_ayahs
+            .filter(ayah_uuid.eq(target_ayah_uuid))
+            .select(ayah_id)
+            .get_result(&mut conn)?;
+
+        conn.transaction(|conn| {
+            // Positions are unique per ayah, they are checked
+            // when the transaction is committed
+            diesel::sql_query("SET CONSTRAINTS quran_words_word_number DEFERRED").execute(conn)?;
+
+            let ayah_words: HashSet<Uuid> = quran_words
+                .filter(word_ayah_id.eq(ayah))
+                .select(word_uuid)
+                .get_results::<Uuid>(conn)?
+                .into_iter()
+                .collect();
+
+            if let Err(detail) = check_ayah_words(&new_words.words, &ayah_words) {
+                return Err(RouterError::from_predefined_with_detail(
+                    "AYAH_WORDS_NOT_VALID",
+                    &detail,
+                ));
+            }
+
+            let kept: Vec<Uuid> = new_words
+                .words
+                .iter()
+                .filter_map(|item| item.uuid)
+                .collect();
+
+            diesel::delete(
+                quran_words
+                    .filter(word_ayah_id.eq(ayah))
+                    .filter(word_uuid.ne_all(&kept)),
+            )
+            .execute(conn)?;
+
+            let split_words: Vec<_> = new_words
+                .words
+                .iter()
+                .map(|item| split_waqf(&item.word))
+                .collect();
+
+            for (index, word) in split_words.iter().enumerate() {
+                check_waqf_word(word).map_err(|err| {
+                    RouterError::from_predefined_with_detail(
+                        "AYAH_WORDS_NOT_VALID",
+                        &format!("word {}: {}", index + 1, err),
+                    )
+                })?;
+            }
+
+            let mut added = vec![];
+
+            for (index, (item, (text, waqf))) in
+                new_words.words.iter().zip(&split_words).enumerate()
+            {
+                let number = index as i32 + 1;
+                let waqf = waqf.map(|waqf| waqf.to_string());
+
+                match item.uuid {
+                    Some(uuid) => {
+                        diesel::update(quran_words.filter(word_uuid.eq(uuid)))
+                            .set((
+                                word_content.eq(text),
+                                word_waqf.eq(waqf),
+                                word_number.eq(number),
+                            ))
+                            .execute(conn)?;
+                    }
+
+                    None => added.push(NewQuranWord {
+                        creator_user_id: user,
+                        ayah_id: ayah,
+                        word: text,
+                        word_number: number,
+                        waqf,
+                    }),
+                }
+            }
+
+            if !added.is_empty() {
+                added.insert_into(quran_words).execute(conn)?;
+            }
+
+            Ok("Updated")
+        })
+    })
+    .await
+    .unwrap()
+}
@@ -1,20 +1,37 @@
 pub mod ayah_add;
+pub mod ayah_context;
 pub mod ayah_delete;
 pub mod ayah_edit;
 pub mod ayah_list;
+pub mod ayah_reference;
+pub mod ayah_tafsir;
 pub mod ayah_view;
+pub mod ayah_words_modify;
 
+use std::collections::HashSet;
 use std::fmt::Display;
 
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::Integer;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::{
     filter::{Filters, Order},
+    routers::{
+        quran::{
+            word::{WaqfMark, WordMorphology, WordScript, WordTranslation},
+            AyahPosition, SurahAyahs,
+        },
+        recitation::AyahAudio,
+    },
+    schema::quran_ayahs,
+    transliteration::Transliteration,
     Format,
 };
 
-#[derive(Deserialize, Serialize)]
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
 #[serde(rename_all = "lowercase")]
 pub enum Sajdah {
     Mostahab,
@@ -43,10 +60,69 @@ impl Sajdah {
     }
 }
 
+/// A typed mark on the ayah
+///
+/// The sajdah is the ayah sajdah, the waqf lazim is a word of the ayah
+/// with the lazim waqf sign, other markers are in the quran_ayah_markers table
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AyahMarker {
+    Sajdah,
+    RubElHizb,
+    WaqfLazim,
+}
+
+impl Display for AyahMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sajdah => write!(f, "sajdah"),
+            Self::RubElHizb => write!(f, "rub_el_hizb"),
+            Self::WaqfLazim => write!(f, "waqf_lazim"),
+        }
+    }
+}
+
+/// Subquery of the id of every ayah that has the marker,
+/// to be used with `eq_any` so the ids are not loaded
+pub fn marked_ayahs(marker: AyahMarker) -> quran_ayahs::BoxedQuery<'static, Pg, Integer> {
+    use crate::schema::quran_ayah_markers::dsl::{
+        ayah_id as marker_ayah_id, marker as marker_name, quran_ayah_markers,
+    };
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, sajdah};
+    use crate::schema::quran_words::dsl::{ayah_id as word_ayah_id, quran_words, waqf};
+
+    let query = quran_ayahs::table.select(ayah_id).into_boxed();
+
+    match marker {
+        AyahMarker::Sajdah => query.filter(sajdah.is_not_null()),
+
+        AyahMarker::WaqfLazim => query.filter(
+            ayah_id.eq_any(
+                quran_words
+                    .filter(waqf.eq(WaqfMark::Lazim.to_string()))
+                    .select(word_ayah_id),
+            ),
+        ),
+
+        _ => query.filter(
+            ayah_id.eq_any(
+                quran_ayah_markers
+                    .filter(marker_name.eq(marker.to_string()))
+                    .select(marker_ayah_id),
+            ),
+        ),
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SimpleWord {
     uuid: Uuid,
     word: String,
+    morphology: Option<WordMorphology>,
+
+    /// Only when the word_lang is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    translation: Option<WordTranslation>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -58,6 +134,73 @@ pub struct AyahWithContent {
     sajdah: Option<Sajdah>,
     text: String,
     words: Vec<SimpleWord>,
+
+    /// Only when the recitation is requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audio: Option<AyahAudio>,
+
+    /// Text in the requested translit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transliteration: Option<String>,
+
+    /// Previous and next ayahs of the mushaf, may be in another surah
+    prev: Option<AyahNeighbour>,
+    next: Option<AyahNeighbour>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AyahNeighbour {
+    pub uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_number: i32,
+}
+
+impl From<(Uuid, AyahPosition)> for AyahNeighbour {
+    fn from((uuid, (surah_number, ayah_number)): (Uuid, AyahPosition)) -> Self {
+        Self {
+            uuid,
+            surah_number,
+            ayah_number,
+        }
+    }
+}
+
+/// Max count of ayahs before and after in /ayah/{uuid}/context
+pub const AYAH_CONTEXT_LIMIT: u32 = 50;
+
+/// The query for /ayah/{uuid}/context
+/// example /ayah/{uuid}/context?before=2&after=2&format=word
+#[derive(Deserialize)]
+pub struct AyahContextQuery {
+    /// Count of the ayahs before, 2 by default
+    before: Option<u32>,
+
+    /// Count of the ayahs after, 2 by default
+    after: Option<u32>,
+
+    #[serde(default)]
+    format: Format,
+}
+
+/// The response type for /ayah/{uuid}/context
+#[derive(Serialize)]
+pub struct AyahContextResponse {
+    pub ayah_uuid: Uuid,
+    pub surahs: Vec<SurahAyahs>,
+}
+
+/// The query for /ayah/{uuid}
+/// example /ayah/{uuid}?word_lang=en&recitation={uuid}
+#[derive(Deserialize)]
+pub struct AyahViewQuery {
+    word_lang: Option<String>,
+    recitation: Option<Uuid>,
+
+    /// Script of the words, the mushaf script when not given
+    script: Option<WordScript>,
+
+    /// Transliteration of the ayah text
+    translit: Option<Transliteration>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -66,10 +209,77 @@ pub struct SimpleAyah {
     pub sajdah: Option<Sajdah>,
 }
 
+/// The query for /ayah/ref/{reference}
+/// example /ayah/ref/2:255-257?mushaf=hafs&format=word
+#[derive(Deserialize)]
+pub struct AyahReferenceQuery {
+    mushaf: String,
+
+    #[serde(default)]
+    format: Format,
+}
+
+/// The response type for /ayah/ref/{reference}
+#[derive(Serialize)]
+pub struct AyahReferenceResponse {
+    /// The resolved reference, like 2:255-257
+    pub reference: String,
+    pub surahs: Vec<SurahAyahs>,
+}
+
+/// A word of the new word list of the ayah,
+/// existing words are kept by their uuid
+#[derive(Deserialize)]
+pub struct AyahWordItem {
+    pub uuid: Option<Uuid>,
+    pub word: String,
+}
+
+/// Request body of /ayah/{uuid}/words, the full word list of the ayah in order
+///
+/// Words of the ayah that are not in the list are deleted
+#[derive(Deserialize)]
+pub struct AyahWords {
+    pub words: Vec<AyahWordItem>,
+}
+
+/// Checks the new word list of the ayah, returns the problem if any
+fn check_ayah_words(words: &[AyahWordItem], ayah_words: &HashSet<Uuid>) -> Result<(), String> {
+    if words.is_empty() {
+        return Err("ayah must have at least one word".to_string());
+    }
+
+    let mut seen = HashSet::new();
+
+    for (index, item) in words.iter().enumerate() {
+        if item.word.trim().is_empty() {
+            return Err(format!("word {} is empty", index + 1));
+        }
+
+        let Some(uuid) = item.uuid else {
+            continue;
+        };
+
+        if !ayah_words.contains(&uuid) {
+            return Err(format!("word {} is not in the ayah", uuid));
+        }
+
+        if !seen.insert(uuid) {
+            return Err(format!("word {} is repeated", uuid));
+        }
+    }
+
+    Ok(())
+}
+
 #[derive(Deserialize, Clone)]
 pub struct AyahListQuery {
     mushaf: String,
     format: Option<Format>,
+    script: Option<WordScript>,
+
+    /// Only the ayahs that have the marker
+    marker: Option<AyahMarker>,
 
     sort: Option<String>,
     order: Option<Order>,
@@ -95,3 +305,41 @@ impl Filters for AyahListQuery {
         self.to
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(uuid: Option<Uuid>, word: &str) -> AyahWordItem {
+        AyahWordItem {
+            uuid,
+            word: word.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_ayah_words() {
+        let first = Uuid::from_u128(1);
+        let second = Uuid::from_u128(2);
+        let ayah_words = HashSet::from([first, second]);
+
+        assert!(check_ayah_words(
+            &[
+                item(Some(second), "b"),
+                item(None, "c"),
+                item(Some(first), "a")
+            ],
+            &ayah_words
+        )
+        .is_ok());
+
+        assert!(check_ayah_words(&[], &ayah_words).is_err());
+        assert!(check_ayah_words(&[item(None, " ")], &ayah_words).is_err());
+        assert!(check_ayah_words(&[item(Some(Uuid::from_u128(3)), "a")], &ayah_words).is_err());
+        assert!(check_ayah_words(
+            &[item(Some(first), "a"), item(Some(first), "a")],
+            &ayah_words
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,65 @@
+use crate::error::RouterError;
+use crate::models::NewQuranDivision;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::{check_division, SimpleDivision};
+
+/// Add's a new division
+pub async fn division_add(
+    new_division: web::Json<SimpleDivision>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_divisions::dsl::{
+        division_type, mushaf_id as division_mushaf_id, number as division_number, quran_divisions,
+    };
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+
+    let new_division = new_division.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(new_division.mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let (start_ayah, end_ayah) = check_division(&mut conn, mushaf, &new_division)?;
+
+        let exists: i64 = quran_divisions
+            .filter(division_mushaf_id.eq(mushaf))
+            .filter(division_type.eq(new_division.division_type.to_string()))
+            .filter(division_number.eq(new_division.number))
+            .count()
+            .get_result(&mut conn)?;
+
+        if exists > 0 {
+            return Err(RouterError::from_predefined("DIVISION_ALREADY_EXISTS"));
+        }
+
+        NewQuranDivision {
+            creator_user_id: user,
+            mushaf_id: mushaf,
+            division_type: new_division.division_type.to_string(),
+            number: new_division.number,
+            start_ayah_id: start_ayah,
+            end_ayah_id: end_ayah,
+        }
+        .insert_into(quran_divisions)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
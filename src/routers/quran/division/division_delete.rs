@@ -0,0 +1,26 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+/// Delete's a single division
+pub async fn division_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_divisions::dsl::{quran_divisions, uuid as division_uuid};
+
+    let target_division_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        diesel::delete(quran_divisions.filter(division_uuid.eq(target_division_uuid)))
+            .execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
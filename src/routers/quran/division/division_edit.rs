@@ -0,0 +1,66 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{check_division, SimpleDivision};
+
+/// Update's single division
+pub async fn division_edit(
+    path: web::Path<Uuid>,
+    new_division: web::Json<SimpleDivision>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_divisions::dsl::{
+        division_type, end_ayah_id, id as division_id, mushaf_id as division_mushaf_id,
+        number as division_number, quran_divisions, start_ayah_id, uuid as division_uuid,
+    };
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+
+    let new_division = new_division.into_inner();
+    let target_division_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let target_division: i32 = quran_divisions
+            .filter(division_uuid.eq(target_division_uuid))
+            .select(division_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(new_division.mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let (start_ayah, end_ayah) = check_division(&mut conn, mushaf, &new_division)?;
+
+        // Another division can't have the same number
+        let exists: i64 = quran_divisions
+            .filter(division_mushaf_id.eq(mushaf))
+            .filter(division_type.eq(new_division.division_type.to_string()))
+            .filter(division_number.eq(new_division.number))
+            .filter(division_id.ne(target_division))
+            .count()
+            .get_result(&mut conn)?;
+
+        if exists > 0 {
+            return Err(RouterError::from_predefined("DIVISION_ALREADY_EXISTS"));
+        }
+
+        diesel::update(quran_divisions.filter(division_id.eq(target_division)))
+            .set((
+                division_mushaf_id.eq(mushaf),
+                division_type.eq(new_division.division_type.to_string()),
+                division_number.eq(new_division.number),
+                start_ayah_id.eq(start_ayah),
+                end_ayah_id.eq(end_ayah),
+            ))
+            .execute(&mut conn)?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::error::RouterError;
+use crate::models::QuranDivision;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{DivisionAyah, DivisionListItem, DivisionListQuery};
+
+/// Returns the list of divisions of a mushaf
+pub async fn division_list(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<DivisionListQuery>,
+) -> Result<web::Json<Vec<DivisionListItem>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_divisions::dsl::{
+        division_type, number as division_number, quran_divisions,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+    use crate::schema::quran_surahs::dsl::{number as surah_number, quran_surahs};
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut divisions_query = quran_divisions
+            .inner_join(quran_mushafs)
+            .filter(mushaf_short_name.eq(query.mushaf))
+            .order((division_type.asc(), division_number.asc()))
+            .select(QuranDivision::as_select())
+            .into_boxed();
+
+        if let Some(requested_type) = query.division_type {
+            divisions_query = divisions_query.filter(division_type.eq(requested_type.to_string()));
+        }
+
+        let divisions = divisions_query.get_results(&mut conn)?;
+
+        let ayah_ids = divisions
+            .iter()
+            .flat_map(|division| [division.start_ayah_id, division.end_ayah_id]);
+
+        let ayahs: HashMap<i32, (Uuid, i32, i32)> = quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(ayah_id.eq_any(ayah_ids))
+            .select((ayah_id, (ayah_uuid, surah_number, ayah_number)))
+            .get_results::<(i32, (Uuid, i32, i32))>(&mut conn)?
+            .into_iter()
+            .collect();
+
+        let division_ayah = |id: i32| {
+            let (uuid, s_number, a_number) = ayahs[&id];
+
+            DivisionAyah {
+                uuid,
+                surah_number: s_number,
+                ayah_number: a_number,
+            }
+        };
+
+        let result = divisions
+            .into_iter()
+            .map(|division| DivisionListItem {
+                uuid: division.uuid,
+                start: division_ayah(division.start_ayah_id),
+                end: division_ayah(division.end_ayah_id),
+                division_type: division.division_type,
+                number: division.number,
+            })
+            .collect();
+
+        Ok(web::Json(result))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,120 @@
+use super::{DivisionNumberViewQuery, DivisionResponse, DivisionType, DivisionViewQuery};
+use crate::error::RouterError;
+use crate::models::{QuranDivision, QuranMushaf};
+use crate::routers::quran::{ayahs_in_range, AyahPosition};
+use crate::{DbPool, Format, SingleSurahMushaf};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Loads the ayahs of the division
+fn division_response(
+    conn: &mut PgConnection,
+    division: QuranDivision,
+    format: Format,
+) -> Result<DivisionResponse, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, id as ayah_id, quran_ayahs};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs};
+    use crate::schema::quran_surahs::dsl::{number as surah_number, quran_surahs};
+
+    let mushaf = quran_mushafs
+        .filter(mushaf_id.eq(division.mushaf_id))
+        .get_result::<QuranMushaf>(conn)?;
+
+    let mut position = |id: i32| {
+        quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(ayah_id.eq(id))
+            .select((surah_number, ayah_number))
+            .get_result::<AyahPosition>(conn)
+    };
+
+    let start = position(division.start_ayah_id)?;
+    let end = position(division.end_ayah_id)?;
+
+    Ok(DivisionResponse {
+        uuid: division.uuid,
+        surahs: ayahs_in_range(conn, mushaf.id, start, end, format)?,
+        mushaf: SingleSurahMushaf::from(mushaf),
+        division_type: division.division_type,
+        number: division.number,
+    })
+}
+
+/// Finds the division by its number in the mushaf
+async fn division_number_view(
+    requested_type: DivisionType,
+    requested_number: i32,
+    query: DivisionNumberViewQuery,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<DivisionResponse>, RouterError> {
+    use crate::schema::quran_divisions::dsl::{
+        division_type, number as division_number, quran_divisions,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let division = quran_divisions
+            .inner_join(quran_mushafs)
+            .filter(mushaf_short_name.eq(query.mushaf))
+            .filter(division_type.eq(requested_type.to_string()))
+            .filter(division_number.eq(requested_number))
+            .select(QuranDivision::as_select())
+            .get_result(&mut conn)?;
+
+        Ok(web::Json(division_response(
+            &mut conn,
+            division,
+            query.format,
+        )?))
+    })
+    .await
+    .unwrap()
+}
+
+/// View a single division with its ayahs
+pub async fn division_view(
+    path: web::Path<Uuid>,
+    web::Query(query): web::Query<DivisionViewQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<DivisionResponse>, RouterError> {
+    use crate::schema::quran_divisions::dsl::{quran_divisions, uuid as division_uuid};
+
+    let requested_division_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let division = quran_divisions
+            .filter(division_uuid.eq(requested_division_uuid))
+            .get_result::<QuranDivision>(&mut conn)?;
+
+        Ok(web::Json(division_response(
+            &mut conn,
+            division,
+            query.format,
+        )?))
+    })
+    .await
+    .unwrap()
+}
+
+/// View a juz of the mushaf, example /juz/30?mushaf=hafs
+pub async fn juz_view(
+    path: web::Path<i32>,
+    web::Query(query): web::Query<DivisionNumberViewQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<DivisionResponse>, RouterError> {
+    division_number_view(DivisionType::Juz, path.into_inner(), query, pool).await
+}
+
+/// View a hizb of the mushaf, example /hizb/60?mushaf=hafs
+pub async fn hizb_view(
+    path: web::Path<i32>,
+    web::Query(query): web::Query<DivisionNumberViewQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<DivisionResponse>, RouterError> {
+    division_number_view(DivisionType::Hizb, path.into_inner(), query, pool).await
+}
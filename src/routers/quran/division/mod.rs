@@ -0,0 +1,149 @@
+pub mod division_add;
+pub mod division_delete;
+pub mod division_edit;
+pub mod division_list;
+pub mod division_view;
+
+use std::fmt::Display;
+
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::RouterError;
+use crate::routers::quran::{ayah_position, AyahPosition, SurahAyahs};
+use crate::{Format, SingleSurahMushaf};
+
+/// The reading divisions of the quran
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DivisionType {
+    Juz,
+    Hizb,
+    Rub,
+    Manzil,
+}
+
+impl Display for DivisionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Juz => write!(f, "juz"),
+            Self::Hizb => write!(f, "hizb"),
+            Self::Rub => write!(f, "rub"),
+            Self::Manzil => write!(f, "manzil"),
+        }
+    }
+}
+
+impl DivisionType {
+    /// Count of this division in a complete mushaf
+    pub fn count(&self) -> i32 {
+        match self {
+            Self::Juz => 30,
+            Self::Hizb => 60,
+            Self::Rub => 240,
+            Self::Manzil => 7,
+        }
+    }
+}
+
+/// User request body type
+#[derive(Deserialize)]
+pub struct SimpleDivision {
+    pub mushaf_uuid: Uuid,
+    pub division_type: DivisionType,
+    pub number: i32,
+    pub start_ayah_uuid: Uuid,
+    pub end_ayah_uuid: Uuid,
+}
+
+/// The query for /division
+/// example /division?mushaf=hafs&division_type=juz
+#[derive(Deserialize)]
+pub struct DivisionListQuery {
+    mushaf: String,
+    division_type: Option<DivisionType>,
+}
+
+/// The query for /division/{uuid}
+/// example /division/{uuid}?format=word
+#[derive(Deserialize)]
+pub struct DivisionViewQuery {
+    #[serde(default)]
+    format: Format,
+}
+
+/// The query for /juz/{number} and /hizb/{number}
+/// example /juz/30?mushaf=hafs&format=word
+#[derive(Deserialize)]
+pub struct DivisionNumberViewQuery {
+    mushaf: String,
+
+    #[serde(default)]
+    format: Format,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct DivisionAyah {
+    pub uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_number: i32,
+}
+
+/// The response type for /division
+#[derive(Serialize, Clone, Debug)]
+pub struct DivisionListItem {
+    pub uuid: Uuid,
+    pub division_type: String,
+    pub number: i32,
+    pub start: DivisionAyah,
+    pub end: DivisionAyah,
+}
+
+/// The response type for /division/{uuid}, /juz/{number} and /hizb/{number}
+#[derive(Serialize, Clone, Debug)]
+pub struct DivisionResponse {
+    pub uuid: Uuid,
+    pub mushaf: SingleSurahMushaf,
+    pub division_type: String,
+    pub number: i32,
+    pub surahs: Vec<SurahAyahs>,
+}
+
+/// Checks the division and returns the ids of the start and end ayahs
+///
+/// Both of the ayahs must be in the mushaf and the start
+/// can't be after the end
+pub fn check_division(
+    conn: &mut PgConnection,
+    mushaf: i32,
+    division: &SimpleDivision,
+) -> Result<(i32, i32), RouterError> {
+    if division.number < 1 || division.number > division.division_type.count() {
+        return Err(RouterError::from_predefined("DIVISION_NUMBER_OUT_OF_RANGE"));
+    }
+
+    let position =
+        |conn: &mut PgConnection, ayah: Uuid| -> Result<(i32, AyahPosition), RouterError> {
+            ayah_position(conn, mushaf, ayah).map_err(|err| match err {
+                diesel::result::Error::NotFound => RouterError::from_predefined_with_detail(
+                    "DIVISION_INVALID_RANGE",
+                    &format!("ayah {} is not in the mushaf", ayah),
+                ),
+
+                err => err.into(),
+            })
+        };
+
+    let (start_id, start) = position(conn, division.start_ayah_uuid)?;
+    let (end_id, end) = position(conn, division.end_ayah_uuid)?;
+
+    if start > end {
+        return Err(RouterError::from_predefined_with_detail(
+            "DIVISION_INVALID_RANGE",
+            "start ayah is after the end ayah",
+        ));
+    }
+
+    Ok((start_id, end_id))
+}
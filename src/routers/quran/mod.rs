@@ -1,5 +1,423 @@
+pub mod ayah;
+pub mod division;
 pub mod mushaf;
 pub mod surah;
-pub mod ayah;
-pub mod word;
 mod test;
+pub mod word;
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use diesel::prelude::*;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::error::RouterError;
+use crate::models::QuranAyah;
+use crate::routers::multip;
+use surah::{AyahTy, AyahWithText, AyahWithWords, Format, SimpleAyah};
+use word::{render_words, WaqfMark};
+
+/// Position of an ayah in the mushaf (surah number, ayah number)
+pub type AyahPosition = (i32, i32);
+
+/// Position of a word in the mushaf (surah number, ayah number,
+/// position of the word in the ayah starting from 1)
+pub type WordPosition = (i32, i32, u32);
+
+/// An ayah range in a single surah of a mushaf, start and end are both included
+#[derive(Clone, Copy, Debug)]
+pub struct AyahRange {
+    pub mushaf: i32,
+    pub surah: i32,
+    pub start: i32,
+    pub end: i32,
+}
+
+/// Mushaf id and position of the start (or end) ayah of a range
+type RangePoint = (i32, AyahPosition);
+
+/// Max count of rows in a single insert query of the mushaf wide imports
+///
+/// Postgres accepts at most 65535 bind params per statement,
+/// so the rows can have at most 6 columns
+pub const MUSHAF_INSERT_CHUNK: usize = 10_000;
+
+/// A position that is shown in the errors, like `ayah 2:255` or `word 2:255:3`
+pub trait Location {
+    fn location(&self) -> String;
+}
+
+impl Location for AyahPosition {
+    fn location(&self) -> String {
+        format!("ayah {}:{}", self.0, self.1)
+    }
+}
+
+impl Location for WordPosition {
+    fn location(&self) -> String {
+        format!("word {}:{}:{}", self.0, self.1, self.2)
+    }
+}
+
+/// Ayahs of a single surah, used when the ayahs are not from one surah
+#[derive(Serialize, Clone, Debug)]
+pub struct SurahAyahs {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub name: String,
+    pub ayahs: Vec<AyahTy>,
+}
+
+/// Returns the position of the ayah, only if the ayah is in the mushaf
+pub fn ayah_position(
+    conn: &mut PgConnection,
+    mushaf: i32,
+    ayah: Uuid,
+) -> QueryResult<(i32, AyahPosition)> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    quran_ayahs
+        .inner_join(quran_surahs)
+        .filter(ayah_uuid.eq(ayah))
+        .filter(mushaf_id.eq(mushaf))
+        .select((ayah_id, (surah_number, ayah_number)))
+        .get_result(conn)
+}
+
+/// Returns the uuid and position of the nearest ayahs after (or before)
+/// the position in the mushaf, nearest first. Crosses into the other surahs
+pub fn nearby_ayahs(
+    conn: &mut PgConnection,
+    mushaf: i32,
+    position: AyahPosition,
+    after: bool,
+    count: i64,
+) -> QueryResult<Vec<(Uuid, AyahPosition)>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    let query = quran_ayahs
+        .inner_join(quran_surahs)
+        .filter(mushaf_id.eq(mushaf))
+        .select((ayah_uuid, (surah_number, ayah_number)))
+        .limit(count)
+        .into_boxed();
+
+    let query = if after {
+        query
+            .filter(
+                surah_number
+                    .gt(position.0)
+                    .or(surah_number.eq(position.0).and(ayah_number.gt(position.1))),
+            )
+            .order((surah_number.asc(), ayah_number.asc()))
+    } else {
+        query
+            .filter(
+                surah_number
+                    .lt(position.0)
+                    .or(surah_number.eq(position.0).and(ayah_number.lt(position.1))),
+            )
+            .order((surah_number.desc(), ayah_number.desc()))
+    };
+
+    query.load(conn)
+}
+
+/// Checks that the range is in a single surah of one mushaf and is in order
+fn check_ayah_range(start: RangePoint, end: RangePoint) -> Result<(), String> {
+    if start.0 != end.0 {
+        return Err("start and end ayahs must be in the same mushaf".to_string());
+    }
+
+    if start.1 .0 != end.1 .0 {
+        return Err("start and end ayahs must be in the same surah".to_string());
+    }
+
+    if start.1 .1 > end.1 .1 {
+        return Err("start ayah is after the end ayah".to_string());
+    }
+
+    Ok(())
+}
+
+/// Returns the start and end ayah ids of the range, when the
+/// mushaf is given the range must be in that mushaf
+pub fn ayah_range(
+    conn: &mut PgConnection,
+    mushaf: Option<i32>,
+    start_uuid: Uuid,
+    end_uuid: Uuid,
+    error_name: &str,
+) -> Result<(i32, i32), RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    let mut point = |uuid: Uuid| -> QueryResult<(i32, RangePoint)> {
+        quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(ayah_uuid.eq(uuid))
+            .select((ayah_id, (mushaf_id, (surah_number, ayah_number))))
+            .get_result(conn)
+    };
+
+    let (start, start_point) = point(start_uuid)?;
+    let (end, end_point) = point(end_uuid)?;
+
+    if mushaf.is_some_and(|mushaf| mushaf != start_point.0) {
+        return Err(RouterError::from_predefined_with_detail(
+            error_name,
+            "ayah is not in the mushaf",
+        ));
+    }
+
+    check_ayah_range(start_point, end_point)
+        .map_err(|err| RouterError::from_predefined_with_detail(error_name, &err))?;
+
+    Ok((start, end))
+}
+
+/// Returns the mushaf, surah and ayah numbers of the ranges
+/// (start and end ayah ids), in the same order
+pub fn ayah_ranges(conn: &mut PgConnection, ranges: &[(i32, i32)]) -> QueryResult<Vec<AyahRange>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, id as ayah_id, quran_ayahs};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    let ids: Vec<i32> = ranges
+        .iter()
+        .flat_map(|(start, end)| [*start, *end])
+        .collect();
+
+    let points: HashMap<i32, RangePoint> = quran_ayahs
+        .inner_join(quran_surahs)
+        .filter(ayah_id.eq_any(ids))
+        .select((ayah_id, (mushaf_id, (surah_number, ayah_number))))
+        .get_results::<(i32, RangePoint)>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(ranges
+        .iter()
+        .map(|(start, end)| {
+            let (mushaf, (surah, start)) = points[start];
+            let (_, (_, end)) = points[end];
+
+            AyahRange {
+                mushaf,
+                surah,
+                start,
+                end,
+            }
+        })
+        .collect())
+}
+
+/// Surah (number, uuid, name), ayah and word (id, text, waqf) of a range
+type RangeWordRow = (
+    (i32, Uuid, String),
+    QuranAyah,
+    (i32, String, Option<String>),
+);
+
+/// Returns the ayahs of the mushaf from start to end (both included)
+/// grouped by surah
+pub fn ayahs_in_range(
+    conn: &mut PgConnection,
+    mushaf: i32,
+    start: AyahPosition,
+    end: AyahPosition,
+    format: Format,
+) -> QueryResult<Vec<SurahAyahs>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs};
+    use crate::schema::quran_surahs::dsl::{
+        mushaf_id, name as surah_name, number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
+    use crate::schema::quran_words::dsl::{
+        id as word_id, quran_words, waqf, word as q_word, word_number,
+    };
+
+    let mut result = quran_ayahs
+        .inner_join(quran_surahs)
+        .inner_join(quran_words)
+        .filter(mushaf_id.eq(mushaf))
+        .filter(
+            surah_number
+                .gt(start.0)
+                .or(surah_number.eq(start.0).and(ayah_number.ge(start.1))),
+        )
+        .filter(
+            surah_number
+                .lt(end.0)
+                .or(surah_number.eq(end.0).and(ayah_number.le(end.1))),
+        )
+        .order((surah_number.asc(), ayah_number.asc(), word_number.asc()))
+        .select((
+            (surah_number, surah_uuid, surah_name),
+            QuranAyah::as_select(),
+            (word_id, q_word, waqf),
+        ))
+        .load::<RangeWordRow>(conn)?;
+
+    render_words(
+        conn,
+        None,
+        true,
+        result.iter_mut().map(|(_, _, (id, word, word_waqf))| {
+            (*id, word, WaqfMark::from_option_string(word_waqf.clone()))
+        }),
+    )?;
+
+    let result = result
+        .into_iter()
+        .map(|(surah, ayah, (_, word, _))| {
+            (
+                (
+                    surah,
+                    SimpleAyah {
+                        number: ayah.ayah_number as u32,
+                        uuid: ayah.uuid,
+                        sajdah: ayah.sajdah,
+                    },
+                ),
+                word,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut surahs: Vec<SurahAyahs> = vec![];
+
+    for (((number, uuid, name), ayah), words) in multip(result, |ayah| ayah) {
+        let ayah = match format {
+            Format::Text => AyahTy::Text(AyahWithText {
+                ayah,
+                text: words.join(" "),
+                transliteration: None,
+                audio: None,
+            }),
+            Format::Word => AyahTy::Words(AyahWithWords {
+                ayah,
+                words,
+                morphology: None,
+                translations: None,
+                tajweed: None,
+                waqf: None,
+                transliteration: None,
+                audio: None,
+            }),
+        };
+
+        match surahs.last_mut() {
+            Some(last) if last.number == number => last.ayahs.push(ayah),
+
+            _ => surahs.push(SurahAyahs {
+                uuid,
+                number,
+                name,
+                ayahs: vec![ayah],
+            }),
+        }
+    }
+
+    Ok(surahs)
+}
+
+/// Returns the id of every ayah of the mushaf by its position
+pub fn ayah_positions(
+    conn: &mut PgConnection,
+    mushaf: i32,
+) -> QueryResult<HashMap<AyahPosition, i32>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, id as ayah_id, quran_ayahs};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+
+    let mushaf_ayahs: Vec<(i32, i32, i32)> = quran_ayahs
+        .inner_join(quran_surahs)
+        .filter(mushaf_id.eq(mushaf))
+        .select((ayah_id, surah_number, ayah_number))
+        .get_results(conn)?;
+
+    Ok(mushaf_ayahs
+        .into_iter()
+        .map(|(id, s_number, a_number)| ((s_number, a_number), id))
+        .collect())
+}
+
+/// Returns the ids of the requested positions in order, every position
+/// must be in the mushaf (`ids` are from ayah_positions or word_positions)
+/// and can't be repeated
+pub fn position_ids<P: Location + Hash + Eq>(
+    ids: &HashMap<P, i32>,
+    positions: impl IntoIterator<Item = P>,
+) -> Result<Vec<i32>, String> {
+    let mut seen: HashSet<i32> = HashSet::new();
+
+    positions
+        .into_iter()
+        .map(|position| {
+            let Some(id) = ids.get(&position) else {
+                return Err(format!("{} is not in the mushaf", position.location()));
+            };
+
+            if !seen.insert(*id) {
+                return Err(format!("{} is repeated", position.location()));
+            }
+
+            Ok(*id)
+        })
+        .collect()
+}
+
+/// Returns the id of every word of the mushaf by its position
+pub fn word_positions(
+    conn: &mut PgConnection,
+    mushaf: i32,
+) -> QueryResult<HashMap<WordPosition, i32>> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number as surah_number, quran_surahs};
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words, word_number};
+
+    let mushaf_words: Vec<(i32, i32, i32, i32)> = quran_words
+        .inner_join(quran_ayahs.inner_join(quran_surahs))
+        .filter(mushaf_id.eq(mushaf))
+        .select((word_id, surah_number, ayah_number, word_number))
+        .get_results(conn)?;
+
+    Ok(mushaf_words
+        .into_iter()
+        .map(|(id, s_number, a_number, w_number)| ((s_number, a_number, w_number as u32), id))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_ids() {
+        let ids: HashMap<WordPosition, i32> = [((1, 1, 1), 10), ((1, 1, 2), 11), ((1, 2, 1), 12)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(
+            position_ids(&ids, [(1, 2, 1), (1, 1, 1)]).unwrap(),
+            vec![12, 10]
+        );
+        assert!(position_ids(&ids, [(1, 1, 1), (1, 1, 1)]).is_err());
+        assert!(position_ids(&ids, [(2, 1, 1)]).is_err());
+        assert_eq!((2, 255).location(), "ayah 2:255");
+    }
+
+    #[test]
+    fn test_check_ayah_range() {
+        assert!(check_ayah_range((1, (1, 1)), (1, (1, 1))).is_ok());
+        assert!(check_ayah_range((1, (2, 1)), (1, (2, 5))).is_ok());
+        assert!(check_ayah_range((1, (2, 5)), (1, (2, 1))).is_err());
+        assert!(check_ayah_range((1, (2, 286)), (1, (3, 1))).is_err());
+        assert!(check_ayah_range((1, (2, 1)), (2, (2, 1))).is_err());
+    }
+}
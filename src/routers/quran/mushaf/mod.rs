@@ -1,13 +1,36 @@
 pub mod mushaf_add;
+pub mod mushaf_compare;
 pub mod mushaf_delete;
 pub mod mushaf_edit;
+pub mod mushaf_export;
+pub mod mushaf_import;
+pub mod mushaf_layout;
+pub mod mushaf_lint;
 pub mod mushaf_list;
+pub mod mushaf_markers;
+pub mod mushaf_morphology;
+pub mod mushaf_page;
+pub mod mushaf_sajdahs;
+pub mod mushaf_tajweed;
 pub mod mushaf_view;
+pub mod mushaf_word_scripts;
+pub mod mushaf_word_translations;
 
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::difference::GetKey;
+use crate::export::ExportFormat;
 use crate::filter::{Filters, Order};
+use crate::import::ImportFormat;
+use crate::lint::LintIssue;
+use crate::models::QuranMushaf;
+use crate::routers::quran::ayah::{AyahMarker, Sajdah};
+use crate::routers::quran::word::WordScript;
+use crate::Format;
+
+/// Max size of the mushaf import file
+pub const MUSHAF_IMPORT_LIMIT: usize = 32 * 1024 * 1024;
 
 #[derive(Serialize)]
 pub struct MushafListItem {
@@ -17,6 +40,33 @@ pub struct MushafListItem {
     pub source: Option<String>,
 }
 
+/// The response type for /mushaf/{uuid}
+#[derive(Serialize)]
+pub struct MushafViewResponse {
+    #[serde(flatten)]
+    pub mushaf: QuranMushaf,
+
+    /// SHA-256 of the surah checksums, changes with the ayahs and words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+}
+
+/// The query for /mushaf/{uuid}
+/// example /mushaf/{uuid}?checksum=true
+#[derive(Deserialize)]
+pub struct MushafViewQuery {
+    /// Hashes every word of the mushaf, so it's only returned when requested
+    #[serde(default)]
+    checksum: bool,
+}
+
+/// The response type for /mushaf/{uuid}/lint
+#[derive(Serialize)]
+pub struct MushafLint {
+    pub mushaf_uuid: Uuid,
+    pub issues: Vec<LintIssue>,
+}
+
 #[derive(Deserialize)]
 pub struct SimpleMushaf {
     short_name: String,
@@ -25,6 +75,81 @@ pub struct SimpleMushaf {
     bismillah_text: Option<String>,
 }
 
+/// The query for /mushaf/import
+/// example /mushaf/import?format=xml&short_name=hafs
+#[derive(Deserialize)]
+pub struct MushafImportQuery {
+    format: ImportFormat,
+    short_name: String,
+    name: Option<String>,
+    source: Option<String>,
+    bismillah_text: Option<String>,
+}
+
+/// The query for /mushaf/{uuid}/export
+/// example /mushaf/{uuid}/export?format=xml&text_format=word
+#[derive(Deserialize)]
+pub struct MushafExportQuery {
+    format: ExportFormat,
+
+    #[serde(default)]
+    text_format: Format,
+}
+
+/// Position of a single word in the printed mushaf
+///
+/// The word is found by its surah number, ayah number and
+/// its position in the ayah (starting from 1)
+#[derive(Deserialize)]
+pub struct WordLayout {
+    surah: i32,
+    ayah: i32,
+    word: u32,
+    page: i32,
+    line: i32,
+}
+
+/// Request body of /mushaf/{uuid}/layout, replaces the whole layout
+#[derive(Deserialize)]
+pub struct MushafLayout {
+    words: Vec<WordLayout>,
+}
+
+#[derive(Serialize)]
+pub struct PageWord {
+    pub uuid: Uuid,
+    pub word: String,
+    pub ayah_uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_number: i32,
+}
+
+#[derive(Serialize)]
+pub struct PageLine {
+    pub number: i32,
+    pub words: Vec<PageWord>,
+}
+
+/// The surah that starts on the page, the header is
+/// rendered before the line
+#[derive(Serialize)]
+pub struct PageSurah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub name: String,
+    pub line: i32,
+    pub bismillah_text: Option<String>,
+}
+
+/// The response type for /mushaf/{uuid}/page/{number}
+#[derive(Serialize)]
+pub struct MushafPage {
+    pub mushaf_uuid: Uuid,
+    pub page: i32,
+    pub surahs: Vec<PageSurah>,
+    pub lines: Vec<PageLine>,
+}
+
 #[derive(Deserialize)]
 pub struct MushafListQuery {
     sort: Option<String>,
@@ -51,3 +176,271 @@ impl Filters for MushafListQuery {
         self.to
     }
 }
+
+/// Translation of a single word, the word is found
+/// like the WordLayout
+#[derive(Deserialize)]
+pub struct WordTranslationItem {
+    surah: i32,
+    ayah: i32,
+    word: u32,
+    text: String,
+    transliteration: Option<String>,
+}
+
+/// Request body of /mushaf/{uuid}/word_translations, replaces
+/// every word translation of the language
+#[derive(Deserialize)]
+pub struct MushafWordTranslations {
+    language: String,
+    words: Vec<WordTranslationItem>,
+}
+
+/// A single word in another script, the word is found
+/// like the WordLayout
+#[derive(Deserialize)]
+pub struct WordScriptItem {
+    surah: i32,
+    ayah: i32,
+    word: u32,
+    text: String,
+}
+
+/// Request body of /mushaf/{uuid}/word_scripts, replaces
+/// every word of the script
+#[derive(Deserialize)]
+pub struct MushafWordScripts {
+    script: WordScript,
+    words: Vec<WordScriptItem>,
+}
+
+/// A prostration ayah of the mushaf
+#[derive(Serialize)]
+pub struct MushafSajdah {
+    pub uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_number: i32,
+    pub sajdah: Sajdah,
+}
+
+/// An ayah found by its surah and ayah number
+#[derive(Deserialize)]
+pub struct MarkerAyahItem {
+    surah: i32,
+    ayah: i32,
+}
+
+/// Request body of /mushaf/{uuid}/markers, replaces
+/// every ayah of the marker
+///
+/// The sajdah marker is set by the ayah sajdah
+#[derive(Deserialize)]
+pub struct MushafMarkers {
+    marker: AyahMarker,
+    ayahs: Vec<MarkerAyahItem>,
+}
+
+/// The query for /mushaf/compare
+/// example /mushaf/compare?base=hafs&other=warsh
+#[derive(Deserialize)]
+pub struct MushafCompareQuery {
+    base: String,
+    other: String,
+}
+
+/// Words of a single ayah, found by its surah and ayah number
+#[derive(Hash, Eq, PartialEq, Clone, Debug)]
+pub struct CompareAyah {
+    surah: i32,
+    ayah: i32,
+    words: Vec<String>,
+}
+
+impl GetKey for CompareAyah {
+    fn get_key(&self) -> String {
+        format!("{}:{}", self.surah, self.ayah)
+    }
+}
+
+/// A single word difference, positions start from 1
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "lowercase", tag = "type")]
+pub enum WordDifference {
+    /// Word is only in the other mushaf
+    Inserted { other_position: usize, word: String },
+
+    /// Word is only in the base mushaf
+    Removed { base_position: usize, word: String },
+
+    /// Word of the base mushaf is replaced in the other mushaf
+    Changed {
+        base_position: usize,
+        other_position: usize,
+        base_word: String,
+        other_word: String,
+    },
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AyahDifferenceStatus {
+    /// Ayah is in both mushafs with different words
+    Changed,
+
+    /// Ayah is only in the other mushaf
+    Inserted,
+
+    /// Ayah is only in the base mushaf
+    Removed,
+}
+
+#[derive(Serialize)]
+pub struct AyahDifference {
+    pub surah_number: i32,
+    pub ayah_number: i32,
+    pub status: AyahDifferenceStatus,
+    pub words: Vec<WordDifference>,
+}
+
+/// The response type for /mushaf/compare
+#[derive(Serialize)]
+pub struct MushafCompare {
+    pub base_uuid: Uuid,
+    pub other_uuid: Uuid,
+    pub ayahs: Vec<AyahDifference>,
+}
+
+/// Aligns the words of two ayahs by their longest common subsequence,
+/// removed and inserted words between the same common words are
+/// paired in order as changed words
+pub fn word_differences(base: &[String], other: &[String]) -> Vec<WordDifference> {
+    // common[i][j] is the length of the common subsequence of
+    // base[i..] and other[j..]
+    let mut common = vec![vec![0usize; other.len() + 1]; base.len() + 1];
+    for i in (0..base.len()).rev() {
+        for j in (0..other.len()).rev() {
+            common[i][j] = if base[i] == other[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = vec![];
+
+    // Indexes of the removed and inserted words since the last common word
+    let mut removed: Vec<usize> = vec![];
+    let mut inserted: Vec<usize> = vec![];
+
+    let mut flush = |removed: &mut Vec<usize>, inserted: &mut Vec<usize>| {
+        let changed = removed.len().min(inserted.len());
+
+        for (&i, &j) in removed.iter().zip(inserted.iter()) {
+            result.push(WordDifference::Changed {
+                base_position: i + 1,
+                other_position: j + 1,
+                base_word: base[i].clone(),
+                other_word: other[j].clone(),
+            });
+        }
+
+        for &i in &removed[changed..] {
+            result.push(WordDifference::Removed {
+                base_position: i + 1,
+                word: base[i].clone(),
+            });
+        }
+
+        for &j in &inserted[changed..] {
+            result.push(WordDifference::Inserted {
+                other_position: j + 1,
+                word: other[j].clone(),
+            });
+        }
+
+        removed.clear();
+        inserted.clear();
+    };
+
+    let (mut i, mut j) = (0, 0);
+    while i < base.len() || j < other.len() {
+        if i < base.len() && j < other.len() && base[i] == other[j] {
+            flush(&mut removed, &mut inserted);
+            i += 1;
+            j += 1;
+        } else if i < base.len() && (j == other.len() || common[i + 1][j] >= common[i][j + 1]) {
+            removed.push(i);
+            i += 1;
+        } else {
+            inserted.push(j);
+            j += 1;
+        }
+    }
+
+    flush(&mut removed, &mut inserted);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<String> {
+        text.split_whitespace().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_word_differences() {
+        assert!(word_differences(&words("a b c"), &words("a b c")).is_empty());
+
+        assert_eq!(
+            word_differences(&words("a b c"), &words("a x c")),
+            vec![WordDifference::Changed {
+                base_position: 2,
+                other_position: 2,
+                base_word: "b".to_string(),
+                other_word: "x".to_string(),
+            }]
+        );
+
+        assert_eq!(
+            word_differences(&words("a c"), &words("a b c")),
+            vec![WordDifference::Inserted {
+                other_position: 2,
+                word: "b".to_string(),
+            }]
+        );
+
+        assert_eq!(
+            word_differences(&words("a b c"), &words("b c")),
+            vec![WordDifference::Removed {
+                base_position: 1,
+                word: "a".to_string(),
+            }]
+        );
+
+        assert_eq!(
+            word_differences(&words("a b c"), &words("x y")),
+            vec![
+                WordDifference::Changed {
+                    base_position: 1,
+                    other_position: 1,
+                    base_word: "a".to_string(),
+                    other_word: "x".to_string(),
+                },
+                WordDifference::Changed {
+                    base_position: 2,
+                    other_position: 2,
+                    base_word: "b".to_string(),
+                    other_word: "y".to_string(),
+                },
+                WordDifference::Removed {
+                    base_position: 3,
+                    word: "c".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use super::{
+    word_differences, AyahDifference, AyahDifferenceStatus, CompareAyah, MushafCompare,
+    MushafCompareQuery,
+};
+use crate::difference::{Difference, DifferenceContext, DifferenceResult};
+use crate::error::RouterError;
+use crate::routers::multip;
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+/// Returns the mushaf uuid and its ayahs in order
+fn mushaf_ayahs(
+    conn: &mut PgConnection,
+    mushaf_short_name: &str,
+) -> QueryResult<(Uuid, Vec<CompareAyah>)> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, short_name, uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, number, quran_surahs};
+    use crate::schema::quran_words::dsl::{quran_words, word, word_number};
+
+    let (mushaf, mushaf_uuid): (i32, Uuid) = quran_mushafs
+        .filter(short_name.eq(mushaf_short_name))
+        .select((mushaf_id, uuid))
+        .get_result(conn)?;
+
+    let words = quran_surahs
+        .filter(surah_mushaf_id.eq(mushaf))
+        .inner_join(quran_ayahs.inner_join(quran_words))
+        .order((number.asc(), ayah_number.asc(), word_number.asc()))
+        .select(((number, ayah_number), word))
+        .load::<((i32, i32), String)>(conn)?;
+
+    let ayahs = multip(words, |position| position)
+        .into_iter()
+        .map(|((surah, ayah), words)| CompareAyah { surah, ayah, words })
+        .collect();
+
+    Ok((mushaf_uuid, ayahs))
+}
+
+/// Compares the words of two mushafs, ayahs are aligned by
+/// their surah and ayah number
+pub async fn mushaf_compare(
+    web::Query(query): web::Query<MushafCompareQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<MushafCompare>, RouterError> {
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (base_uuid, base) = mushaf_ayahs(&mut conn, &query.base)?;
+        let (other_uuid, other) = mushaf_ayahs(&mut conn, &query.other)?;
+
+        let mut difference = Difference::from(DifferenceContext::new(base, other));
+
+        let mut ayahs: Vec<AyahDifference> = difference
+            .diff()
+            .into_iter()
+            .map(|result| {
+                let (status, ayah, words) = match result {
+                    DifferenceResult::Update(base, other) => (
+                        AyahDifferenceStatus::Changed,
+                        (base.surah, base.ayah),
+                        word_differences(&base.words, &other.words),
+                    ),
+
+                    DifferenceResult::Insert(other) => (
+                        AyahDifferenceStatus::Inserted,
+                        (other.surah, other.ayah),
+                        word_differences(&[], &other.words),
+                    ),
+
+                    DifferenceResult::Remove(base) => (
+                        AyahDifferenceStatus::Removed,
+                        (base.surah, base.ayah),
+                        word_differences(&base.words, &[]),
+                    ),
+                };
+
+                AyahDifference {
+                    surah_number: ayah.0,
+                    ayah_number: ayah.1,
+                    status,
+                    words,
+                }
+            })
+            .collect();
+
+        ayahs.sort_by_key(|ayah| (ayah.surah_number, ayah.ayah_number));
+
+        Ok(web::Json(MushafCompare {
+            base_uuid,
+            other_uuid,
+            ayahs,
+        }))
+    })
+    .await
+    .unwrap()
+}
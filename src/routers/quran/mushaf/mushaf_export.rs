@@ -0,0 +1,169 @@
+use crate::error::RouterError;
+use crate::export::{ExportMushaf, ExportSurah, ExportWriter};
+use crate::models::{QuranAyah, QuranMushaf, QuranSurah};
+use crate::routers::multip;
+use crate::routers::quran::surah::{AyahTy, AyahWithText, AyahWithWords, Format, SimpleAyah};
+use crate::routers::quran::word::{with_waqf, WaqfMark};
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::http::header::{ContentDisposition, DispositionParam, DispositionType};
+use actix_web::web::{self, Bytes};
+use actix_web::HttpResponse;
+use diesel::prelude::*;
+use futures_util::future::ready;
+use futures_util::stream::{self, StreamExt};
+
+use super::MushafExportQuery;
+
+/// Returns the ayahs of the surah in the requested text format
+fn surah_ayahs(
+    pool: &DbPool,
+    target_surah_id: i32,
+    text_format: &Format,
+) -> Result<Vec<AyahTy>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, surah_id};
+    use crate::schema::quran_words::dsl::{quran_words, waqf, word as q_word, word_number};
+
+    let mut conn = pool.get().unwrap();
+
+    let result = quran_ayahs
+        .filter(surah_id.eq(target_surah_id))
+        .inner_join(quran_words)
+        .order((ayah_number.asc(), word_number.asc()))
+        .select((QuranAyah::as_select(), (q_word, waqf)))
+        .load::<(QuranAyah, (String, Option<String>))>(&mut conn)?;
+
+    // The waqf signs are exported in the words, like the imported text
+    let result: Vec<(QuranAyah, String)> = result
+        .into_iter()
+        .map(|(ayah, (word, word_waqf))| {
+            (
+                ayah,
+                with_waqf(word, WaqfMark::from_option_string(word_waqf)),
+            )
+        })
+        .collect();
+
+    let ayahs_as_map = multip(result, |ayah| SimpleAyah {
+        number: ayah.ayah_number as u32,
+        uuid: ayah.uuid,
+        sajdah: ayah.sajdah,
+    });
+
+    Ok(ayahs_as_map
+        .into_iter()
+        .map(|(ayah, words)| match text_format {
+            Format::Text => AyahTy::Text(AyahWithText {
+                ayah,
+                text: words.join(" "),
+                transliteration: None,
+                audio: None,
+            }),
+            Format::Word => AyahTy::Words(AyahWithWords {
+                ayah,
+                words,
+                morphology: None,
+                translations: None,
+                tajweed: None,
+                waqf: None,
+                transliteration: None,
+                audio: None,
+            }),
+        })
+        .collect())
+}
+
+/// Exports the whole mushaf as json, xml or csv
+///
+/// The response is streamed surah by surah
+pub async fn mushaf_export(
+    path: web::Path<Uuid>,
+    web::Query(query): web::Query<MushafExportQuery>,
+    pool: web::Data<DbPool>,
+) -> Result<HttpResponse, RouterError> {
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id, number, quran_surahs};
+
+    let requested_mushaf_uuid = path.into_inner();
+    let pool = pool.into_inner();
+    let list_pool = pool.clone();
+
+    let (mushaf, surahs) = web::block(move || {
+        let mut conn = list_pool.get().unwrap();
+
+        let mushaf: QuranMushaf = quran_mushafs
+            .filter(mushaf_uuid.eq(requested_mushaf_uuid))
+            .get_result(&mut conn)?;
+
+        let surahs: Vec<QuranSurah> = quran_surahs
+            .filter(mushaf_id.eq(mushaf.id))
+            .order(number.asc())
+            .get_results(&mut conn)?;
+
+        Ok::<_, RouterError>((mushaf, surahs))
+    })
+    .await
+    .unwrap()?;
+
+    let export_mushaf = ExportMushaf {
+        uuid: mushaf.uuid,
+        short_name: mushaf.short_name,
+        name: mushaf.name,
+        source: mushaf.source,
+        bismillah_text: mushaf.bismillah_text,
+    };
+
+    let format = query.format;
+    let text_format = query.text_format;
+
+    let writer = ExportWriter::new(format, text_format, &export_mushaf);
+    let begin = writer.begin();
+    let end = writer.end();
+
+    let file_name = format!(
+        "{}.{}",
+        export_mushaf
+            .short_name
+            .clone()
+            .unwrap_or(export_mushaf.uuid.to_string()),
+        format.extension()
+    );
+
+    let surahs_stream = stream::iter(surahs.into_iter().enumerate()).then(move |(index, surah)| {
+        let pool = pool.clone();
+        let export_mushaf = export_mushaf.clone();
+
+        async move {
+            let ayahs = web::block(move || surah_ayahs(&pool, surah.id, &text_format))
+                .await
+                .unwrap()?;
+
+            let export_surah = ExportSurah {
+                uuid: surah.uuid,
+                number: surah.number,
+                name: surah.name,
+                name_transliteration: surah.name_transliteration,
+                period: surah.period,
+                bismillah_status: surah.bismillah_status,
+                bismillah_as_first_ayah: surah.bismillah_as_first_ayah,
+                ayahs,
+            };
+
+            let writer = ExportWriter::new(format, text_format, &export_mushaf);
+
+            Ok::<_, RouterError>(Bytes::from(writer.surah(index, &export_surah)))
+        }
+    });
+
+    let body = stream::once(ready(Ok(Bytes::from(begin))))
+        .chain(surahs_stream)
+        .chain(stream::once(ready(Ok(Bytes::from(end)))));
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .insert_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(file_name)],
+        })
+        .streaming(body))
+}
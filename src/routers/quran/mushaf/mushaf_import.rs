@@ -0,0 +1,49 @@
+use crate::error::RouterError;
+use crate::import::{import_mushaf, ImportMushaf, ImportReport, ParsedMushaf};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::MushafImportQuery;
+
+/// Creates a whole mushaf from a tanzil xml or text file
+///
+/// The file is the request body, mushaf info comes from the query
+pub async fn mushaf_import(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<MushafImportQuery>,
+    body: String,
+    data: web::ReqData<u32>,
+) -> Result<web::Json<ImportReport>, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+
+    let data = data.into_inner();
+
+    web::block(move || {
+        // Parse before getting a connection, the file may be invalid
+        let parsed = ParsedMushaf::parse(&query.format, &body)?;
+
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let report = import_mushaf(
+            &mut conn,
+            user,
+            ImportMushaf {
+                short_name: &query.short_name,
+                name: query.name.as_deref(),
+                source: query.source.as_deref(),
+                bismillah_text: query.bismillah_text,
+            },
+            parsed,
+        )?;
+
+        Ok(web::Json(report))
+    })
+    .await
+    .unwrap()
+}
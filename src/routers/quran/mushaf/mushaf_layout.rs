@@ -0,0 +1,94 @@
+use crate::error::RouterError;
+use crate::models::NewQuranWordLayout;
+use crate::routers::quran::{position_ids, word_positions, Location, MUSHAF_INSERT_CHUNK};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::MushafLayout;
+
+/// Replaces the page and line layout of the mushaf words
+pub async fn mushaf_layout(
+    path: web::Path<Uuid>,
+    layout: web::Json<MushafLayout>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, quran_surahs};
+    use crate::schema::quran_word_layouts::dsl::{quran_word_layouts, word_id as layout_word_id};
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words};
+
+    let target_mushaf_uuid = path.into_inner();
+    let layout = layout.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(target_mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let positions = word_positions(&mut conn, mushaf)?;
+
+        let ids = position_ids(
+            &positions,
+            layout
+                .words
+                .iter()
+                .map(|word| (word.surah, word.ayah, word.word)),
+        )
+        .map_err(|err| RouterError::from_predefined_with_detail("MUSHAF_LAYOUT_NOT_VALID", &err))?;
+
+        let mut new_layouts: Vec<NewQuranWordLayout> = Vec::with_capacity(layout.words.len());
+
+        for (word, id) in layout.words.into_iter().zip(ids) {
+            if word.page < 1 || word.line < 1 {
+                return Err(RouterError::from_predefined_with_detail(
+                    "MUSHAF_LAYOUT_NOT_VALID",
+                    &format!(
+                        "page and line of {} must be positive",
+                        (word.surah, word.ayah, word.word).location()
+                    ),
+                ));
+            }
+
+            new_layouts.push(NewQuranWordLayout {
+                creator_user_id: user,
+                word_id: id,
+                page: word.page,
+                line: word.line,
+            });
+        }
+
+        conn.transaction(|conn| {
+            let mushaf_word_ids = quran_words
+                .inner_join(quran_ayahs.inner_join(quran_surahs))
+                .filter(surah_mushaf_id.eq(mushaf))
+                .select(word_id);
+
+            diesel::delete(quran_word_layouts.filter(layout_word_id.eq_any(mushaf_word_ids)))
+                .execute(conn)?;
+
+            for chunk in new_layouts.chunks(MUSHAF_INSERT_CHUNK) {
+                chunk.insert_into(quran_word_layouts).execute(conn)?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        })?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use super::MushafLint;
+use crate::error::RouterError;
+use crate::lint::{lint_mushaf, LintAyah, LintSurah, LintWord};
+use crate::models::{QuranMushaf, QuranSurah};
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+/// Reports the structural problems of the mushaf
+pub async fn mushaf_lint(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<MushafLint>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, sajdah, surah_id as ayah_surah_id,
+        uuid as ayah_uuid,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, number, quran_surahs};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, quran_words, uuid as word_uuid, word, word_number,
+    };
+
+    let requested_mushaf_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mushaf: QuranMushaf = quran_mushafs
+            .filter(mushaf_uuid.eq(requested_mushaf_uuid))
+            .get_result(&mut conn)?;
+
+        let surahs: Vec<QuranSurah> = quran_surahs
+            .filter(surah_mushaf_id.eq(mushaf.id))
+            .order(number.asc())
+            .load(&mut conn)?;
+
+        let surah_ids: Vec<i32> = surahs.iter().map(|surah| surah.id).collect();
+
+        // Ayahs without words must be in the report, so the
+        // words are loaded separately
+        let ayahs: Vec<(i32, i32, Uuid, i32, Option<String>)> = quran_ayahs
+            .filter(ayah_surah_id.eq_any(&surah_ids))
+            .order(ayah_number.asc())
+            .select((ayah_surah_id, ayah_id, ayah_uuid, ayah_number, sajdah))
+            .load(&mut conn)?;
+
+        let mut words: HashMap<i32, Vec<LintWord>> = HashMap::new();
+        for (id, uuid, text) in quran_words
+            .inner_join(quran_ayahs)
+            .filter(ayah_surah_id.eq_any(&surah_ids))
+            .order(word_number.asc())
+            .select((word_ayah_id, word_uuid, word))
+            .load::<(i32, Uuid, String)>(&mut conn)?
+        {
+            words
+                .entry(id)
+                .or_default()
+                .push(LintWord { uuid, word: text });
+        }
+
+        let mut surah_ayahs: HashMap<i32, Vec<LintAyah>> = HashMap::new();
+        for (surah, id, uuid, ayah, ayah_sajdah) in ayahs {
+            surah_ayahs.entry(surah).or_default().push(LintAyah {
+                uuid,
+                number: ayah,
+                sajdah: ayah_sajdah,
+                words: words.remove(&id).unwrap_or_default(),
+            });
+        }
+
+        let lint_surahs: Vec<LintSurah> = surahs
+            .into_iter()
+            .map(|surah| LintSurah {
+                uuid: surah.uuid,
+                number: surah.number,
+                bismillah_status: surah.bismillah_status,
+                bismillah_as_first_ayah: surah.bismillah_as_first_ayah,
+                ayahs: surah_ayahs.remove(&surah.id).unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(web::Json(MushafLint {
+            mushaf_uuid: mushaf.uuid,
+            issues: lint_mushaf(&lint_surahs, mushaf.bismillah_text.as_deref()),
+        }))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,98 @@
+use crate::error::RouterError;
+use crate::models::NewQuranAyahMarker;
+use crate::routers::quran::ayah::AyahMarker;
+use crate::routers::quran::{ayah_positions, position_ids, MUSHAF_INSERT_CHUNK};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::MushafMarkers;
+
+/// Replaces the ayahs of the mushaf that have the marker
+pub async fn mushaf_markers(
+    path: web::Path<Uuid>,
+    markers: web::Json<MushafMarkers>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_ayah_markers::dsl::{
+        ayah_id as marker_ayah_id, marker, quran_ayah_markers,
+    };
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, quran_surahs};
+
+    let target_mushaf_uuid = path.into_inner();
+    let markers = markers.into_inner();
+    let data = data.into_inner();
+
+    // These markers come from the ayahs and words themselves
+    let derived = match markers.marker {
+        AyahMarker::Sajdah => Some("sajdah is set by the ayah sajdah"),
+        AyahMarker::WaqfLazim => Some("waqf lazim is set by the waqf signs of the words"),
+        AyahMarker::RubElHizb => None,
+    };
+
+    if let Some(detail) = derived {
+        return Err(RouterError::from_predefined_with_detail(
+            "AYAH_MARKERS_NOT_VALID",
+            detail,
+        ));
+    }
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(target_mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let positions = ayah_positions(&mut conn, mushaf)?;
+        let marker_name = markers.marker.to_string();
+
+        let new_markers: Vec<NewQuranAyahMarker> = position_ids(
+            &positions,
+            markers.ayahs.iter().map(|ayah| (ayah.surah, ayah.ayah)),
+        )
+        .map_err(|err| RouterError::from_predefined_with_detail("AYAH_MARKERS_NOT_VALID", &err))?
+        .into_iter()
+        .map(|id| NewQuranAyahMarker {
+            creator_user_id: user,
+            ayah_id: id,
+            marker: &marker_name,
+        })
+        .collect();
+
+        conn.transaction(|conn| {
+            let mushaf_ayah_ids = quran_ayahs
+                .inner_join(quran_surahs)
+                .filter(surah_mushaf_id.eq(mushaf))
+                .select(ayah_id);
+
+            diesel::delete(
+                quran_ayah_markers
+                    .filter(marker_ayah_id.eq_any(mushaf_ayah_ids))
+                    .filter(marker.eq(&marker_name)),
+            )
+            .execute(conn)?;
+
+            for chunk in new_markers.chunks(MUSHAF_INSERT_CHUNK) {
+                chunk.insert_into(quran_ayah_markers).execute(conn)?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        })?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
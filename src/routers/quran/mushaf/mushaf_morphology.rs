@@ -0,0 +1,45 @@
+use crate::error::RouterError;
+use crate::morphology::{import_morphology, parse_corpus, MorphologyReport};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Replaces the word morphology of the mushaf
+///
+/// The body is the Quranic Arabic Corpus morphology file
+pub async fn mushaf_morphology(
+    path: web::Path<Uuid>,
+    body: String,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<web::Json<MorphologyReport>, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+
+    let target_mushaf_uuid = path.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        // Parse before getting a connection, the file may be invalid
+        let words = parse_corpus(&body)?;
+
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(target_mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let report = import_morphology(&mut conn, user, mushaf, words)?;
+
+        Ok(web::Json(report))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use super::{MushafPage, PageLine, PageSurah, PageWord};
+use crate::error::RouterError;
+use crate::models::QuranMushaf;
+use crate::routers::quran::word::{render_words, WaqfMark};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// A word on the page with its ayah and surah
+type PageWordRow = (
+    (i32, i32, Uuid, String, Option<String>),
+    (i32, Uuid, i32),
+    (i32, Uuid, String, bool, bool),
+);
+
+/// Returns a single page of the printed mushaf, line by line
+pub async fn mushaf_page(
+    path: web::Path<(Uuid, i32)>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<MushafPage>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{
+        bismillah_as_first_ayah, bismillah_status, mushaf_id as surah_mushaf_id,
+        name as surah_name, number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
+    use crate::schema::quran_word_layouts::dsl::{line, page, quran_word_layouts};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, id as word_id, quran_words, uuid as word_uuid, waqf,
+        word as q_word, word_number,
+    };
+
+    let (requested_mushaf_uuid, requested_page) = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mushaf: QuranMushaf = quran_mushafs
+            .filter(mushaf_uuid.eq(requested_mushaf_uuid))
+            .get_result(&mut conn)?;
+
+        let mut words: Vec<PageWordRow> = quran_word_layouts
+            .inner_join(quran_words.inner_join(quran_ayahs.inner_join(quran_surahs)))
+            .filter(surah_mushaf_id.eq(mushaf.id))
+            .filter(page.eq(requested_page))
+            .order((
+                line.asc(),
+                surah_number.asc(),
+                ayah_number.asc(),
+                word_number.asc(),
+            ))
+            .select((
+                (line, word_id, word_uuid, q_word, waqf),
+                (ayah_id, ayah_uuid, ayah_number),
+                (
+                    surah_number,
+                    surah_uuid,
+                    surah_name,
+                    bismillah_status,
+                    bismillah_as_first_ayah,
+                ),
+            ))
+            .get_results(&mut conn)?;
+
+        if words.is_empty() {
+            return Err(RouterError::from_predefined("NOT_FOUND"));
+        }
+
+        render_words(
+            &mut conn,
+            None,
+            true,
+            words.iter_mut().map(|((_, id, _, word, word_waqf), _, _)| {
+                (*id, word, WaqfMark::from_option_string(word_waqf.clone()))
+            }),
+        )?;
+
+        // The surah starts on this page if the
+        // first word of its first ayah is on this page
+        let first_ayahs: Vec<i32> = words
+            .iter()
+            .filter(|(_, (_, _, a_number), _)| *a_number == 1)
+            .map(|(_, (id, _, _), _)| *id)
+            .collect();
+
+        let mut first_words: HashMap<i32, i32> = HashMap::new();
+
+        for (a_id, w_id) in quran_words
+            .filter(word_ayah_id.eq_any(first_ayahs))
+            .order((word_ayah_id.asc(), word_number.asc()))
+            .select((word_ayah_id, word_id))
+            .get_results::<(i32, i32)>(&mut conn)?
+        {
+            first_words.entry(a_id).or_insert(w_id);
+        }
+
+        let mut surahs: Vec<PageSurah> = vec![];
+        let mut lines: Vec<PageLine> = vec![];
+
+        for ((w_line, w_id, w_uuid, word, _), (a_id, a_uuid, a_number), surah) in words {
+            let (s_number, s_uuid, s_name, s_bismillah_status, s_bismillah_as_first_ayah) = surah;
+
+            if first_words.get(&a_id) == Some(&w_id) {
+                surahs.push(PageSurah {
+                    uuid: s_uuid,
+                    number: s_number,
+                    name: s_name,
+                    line: w_line,
+                    bismillah_text: if s_bismillah_status && !s_bismillah_as_first_ayah {
+                        mushaf.bismillah_text.clone()
+                    } else {
+                        None
+                    },
+                });
+            }
+
+            let page_word = PageWord {
+                uuid: w_uuid,
+                word,
+                ayah_uuid: a_uuid,
+                surah_number: s_number,
+                ayah_number: a_number,
+            };
+
+            match lines.last_mut() {
+                Some(last) if last.number == w_line => last.words.push(page_word),
+
+                _ => lines.push(PageLine {
+                    number: w_line,
+                    words: vec![page_word],
+                }),
+            }
+        }
+
+        Ok(web::Json(MushafPage {
+            mushaf_uuid: mushaf.uuid,
+            page: requested_page,
+            surahs,
+            lines,
+        }))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,54 @@
+use super::MushafSajdah;
+use crate::error::RouterError;
+use crate::routers::quran::ayah::Sajdah;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Returns every prostration ayah of the mushaf in order
+pub async fn mushaf_sajdahs(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<Vec<MushafSajdah>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, sajdah, uuid as ayah_uuid};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{
+        mushaf_id as surah_mushaf_id, number as surah_number, quran_surahs,
+    };
+
+    let requested_mushaf_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(requested_mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let ayahs: Vec<(Uuid, i32, i32, Option<String>)> = quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(surah_mushaf_id.eq(mushaf))
+            .filter(sajdah.is_not_null())
+            .order((surah_number.asc(), ayah_number.asc()))
+            .select((ayah_uuid, surah_number, ayah_number, sajdah))
+            .load(&mut conn)?;
+
+        Ok(web::Json(
+            ayahs
+                .into_iter()
+                .filter_map(|(uuid, s_number, a_number, ayah_sajdah)| {
+                    Some(MushafSajdah {
+                        uuid,
+                        surah_number: s_number,
+                        ayah_number: a_number,
+                        sajdah: Sajdah::from_option_string(ayah_sajdah)?,
+                    })
+                })
+                .collect(),
+        ))
+    })
+    .await
+    .unwrap()
+}
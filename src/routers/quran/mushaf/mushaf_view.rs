@@ -1,3 +1,5 @@
+use super::{MushafViewQuery, MushafViewResponse};
+use crate::checksum::mushaf_checksum;
 use crate::error::RouterError;
 use crate::models::QuranMushaf;
 use crate::DbPool;
@@ -5,11 +7,12 @@ use ::uuid::Uuid;
 use actix_web::web;
 use diesel::prelude::*;
 
-/// Return's a single mushaf
+/// Return's a single mushaf, with its checksum if requested
 pub async fn mushaf_view(
     path: web::Path<Uuid>,
     pool: web::Data<DbPool>,
-) -> Result<web::Json<QuranMushaf>, RouterError> {
+    web::Query(query): web::Query<MushafViewQuery>,
+) -> Result<web::Json<MushafViewResponse>, RouterError> {
     use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
 
     let requested_mushaf_uuid = path.into_inner();
@@ -22,7 +25,16 @@ pub async fn mushaf_view(
             .filter(mushaf_uuid.eq(requested_mushaf_uuid))
             .get_result(&mut conn)?;
 
-        Ok(web::Json(result))
+        let checksum = if query.checksum {
+            Some(mushaf_checksum(&mut conn, result.id)?)
+        } else {
+            None
+        };
+
+        Ok(web::Json(MushafViewResponse {
+            mushaf: result,
+            checksum,
+        }))
     })
     .await
     .unwrap()
@@ -0,0 +1,94 @@
+use crate::error::RouterError;
+use crate::models::NewQuranWordTranslation;
+use crate::routers::quran::{position_ids, word_positions, MUSHAF_INSERT_CHUNK};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::MushafWordTranslations;
+
+/// Replaces the word by word translations of the mushaf in a language
+pub async fn mushaf_word_translations(
+    path: web::Path<Uuid>,
+    translations: web::Json<MushafWordTranslations>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, quran_surahs};
+    use crate::schema::quran_word_translations::dsl::{
+        language, quran_word_translations, word_id as translation_word_id,
+    };
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words};
+
+    let target_mushaf_uuid = path.into_inner();
+    let translations = translations.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(target_mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        let positions = word_positions(&mut conn, mushaf)?;
+
+        let ids = position_ids(
+            &positions,
+            translations
+                .words
+                .iter()
+                .map(|word| (word.surah, word.ayah, word.word)),
+        )
+        .map_err(|err| {
+            RouterError::from_predefined_with_detail("WORD_TRANSLATIONS_NOT_VALID", &err)
+        })?;
+
+        let new_translations: Vec<NewQuranWordTranslation> = translations
+            .words
+            .iter()
+            .zip(ids)
+            .map(|(word, id)| NewQuranWordTranslation {
+                creator_user_id: user,
+                word_id: id,
+                language: &translations.language,
+                text: &word.text,
+                transliteration: word.transliteration.as_deref(),
+            })
+            .collect();
+
+        conn.transaction(|conn| {
+            let mushaf_word_ids = quran_words
+                .inner_join(quran_ayahs.inner_join(quran_surahs))
+                .filter(surah_mushaf_id.eq(mushaf))
+                .select(word_id);
+
+            diesel::delete(
+                quran_word_translations
+                    .filter(translation_word_id.eq_any(mushaf_word_ids))
+                    .filter(language.eq(&translations.language)),
+            )
+            .execute(conn)?;
+
+            for chunk in new_translations.chunks(MUSHAF_INSERT_CHUNK) {
+                chunk.insert_into(quran_word_translations).execute(conn)?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        })?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -4,17 +4,30 @@ pub mod surah_edit;
 pub mod surah_list;
 pub mod surah_view;
 
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::hash::Hash;
 
 use crate::{
     filter::{Filters, Order},
     models::QuranMushaf,
+    routers::{
+        quran::{
+            ayah::AyahMarker,
+            division::DivisionType,
+            word::{WaqfMark, WordMorphology, WordScript, WordTajweed, WordTranslation},
+            AyahPosition,
+        },
+        recitation::AyahAudio,
+    },
+    transliteration::Transliteration,
 };
+use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// The quran text format Each word has its own uuid
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Format {
     Text,
@@ -27,6 +40,23 @@ impl Default for Format {
     }
 }
 
+/// Where the surah was revealed
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SurahPeriod {
+    Makki,
+    Madani,
+}
+
+impl Display for SurahPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Makki => write!(f, "makki"),
+            Self::Madani => write!(f, "madani"),
+        }
+    }
+}
+
 // This is for Surah router
 // which is faster than SimpleAyah in sorting
 #[derive(Eq, Serialize, Clone, Debug)]
@@ -76,6 +106,14 @@ pub struct AyahWithText {
     #[serde(flatten)]
     pub ayah: SimpleAyah,
     pub text: String,
+
+    /// Text in the requested translit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+
+    /// Audio of the requested recitation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<AyahAudio>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -83,6 +121,31 @@ pub struct AyahWithWords {
     #[serde(flatten)]
     pub ayah: SimpleAyah,
     pub words: Vec<String>,
+
+    /// Morphology of each word, in the same order as words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub morphology: Option<Vec<Option<WordMorphology>>>,
+
+    /// Translation of each word in the requested word_lang,
+    /// in the same order as words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Vec<Option<WordTranslation>>>,
+
+    /// Tajweed annotations of each word, in the same order as words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tajweed: Option<Vec<Vec<WordTajweed>>>,
+
+    /// Waqf sign after each word, in the same order as words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub waqf: Option<Vec<Option<WaqfMark>>>,
+
+    /// Text of the ayah in the requested translit
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transliteration: Option<String>,
+
+    /// Audio of the requested recitation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio: Option<AyahAudio>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -108,6 +171,33 @@ pub struct GetSurahQuery {
     format: Format,
 
     lang_code: Option<String>,
+
+    /// Language of the word translations, only for the word format
+    word_lang: Option<String>,
+
+    recitation: Option<Uuid>,
+
+    /// Script of the words, the mushaf script when not given
+    script: Option<WordScript>,
+
+    /// Tajweed annotations of the words, only for the word format.
+    /// The ranges are of the mushaf script
+    #[serde(default)]
+    tajweed: bool,
+
+    /// Only the ayahs that have the marker
+    marker: Option<AyahMarker>,
+
+    /// Waqf signs in the text, true by default
+    waqf: Option<bool>,
+
+    /// Transliteration of the ayah texts
+    translit: Option<Transliteration>,
+
+    /// Checksum of the surah, loads every word of the surah again
+    /// so it's only returned when requested
+    #[serde(default)]
+    checksum: bool,
 }
 
 /// The query needs the mushaf
@@ -181,6 +271,15 @@ pub struct SingleSurahResponse {
     pub bismillah_as_first_ayah: bool,
     pub bismillah_text: Option<String>,
     pub number_of_ayahs: i64,
+    pub revelation_order: Option<i32>,
+    pub rukus: Option<i32>,
+
+    #[serde(flatten)]
+    pub start: SurahStart,
+
+    /// SHA-256 of the surah text, changes with the ayahs and words
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
 }
 
 /// The response type for /surah
@@ -190,10 +289,22 @@ pub struct SurahListResponse {
     pub number: i32,
     pub period: Option<String>,
     pub number_of_ayahs: i64,
+    pub revelation_order: Option<i32>,
+    pub rukus: Option<i32>,
+
+    #[serde(flatten)]
+    pub start: SurahStart,
     pub names: Vec<SurahName>,
 }
 
-// TODO: Remove number. number must be generated at api runtime
+/// The juz and page where the surah starts, only known when
+/// the mushaf has the juz divisions and the layout
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct SurahStart {
+    pub start_juz: Option<i32>,
+    pub start_page: Option<i32>,
+}
+
 /// User request body type
 #[derive(Serialize, Clone, Debug, Deserialize)]
 pub struct SimpleSurah {
@@ -201,9 +312,131 @@ pub struct SimpleSurah {
     pub name_pronunciation: Option<String>,
     pub name_translation_phrase: Option<String>,
     pub name_transliteration: Option<String>,
-    pub period: Option<String>,
-    pub number: i32,
+    pub period: Option<SurahPeriod>,
+
+    /// Position of the surah in the order of revelation
+    pub revelation_order: Option<i32>,
+    pub rukus: Option<i32>,
+
+    /// Position of the surah in the mushaf, the surahs after it are shifted.
+    /// When not given the surah is added to the end (or keeps its number on edit)
+    pub number: Option<i32>,
     pub bismillah_status: bool,
     pub bismillah_as_first_ayah: bool,
     pub mushaf_uuid: Uuid,
 }
+
+/// Checks the revelation order and the rukus count of the surah
+pub fn check_surah_metadata(surah: &SimpleSurah) -> Result<(), String> {
+    if let Some(order) = surah.revelation_order {
+        if order < 1 {
+            return Err(format!("revelation order {} is not valid", order));
+        }
+    }
+
+    if let Some(rukus) = surah.rukus {
+        if rukus < 0 {
+            return Err(format!("rukus count {} is not valid", rukus));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the start juz and page of the surahs of the mushaf by surah id
+pub fn surah_starts(
+    conn: &mut PgConnection,
+    mushaf: i32,
+    surahs: &[i32],
+) -> QueryResult<HashMap<i32, SurahStart>> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, surah_id as ayah_surah_id,
+    };
+    use crate::schema::quran_divisions::dsl::{
+        division_type, end_ayah_id, mushaf_id as division_mushaf_id, number as division_number,
+        quran_divisions, start_ayah_id,
+    };
+    use crate::schema::quran_surahs::dsl::{id as surah_id, number as surah_number, quran_surahs};
+    use crate::schema::quran_word_layouts::dsl::{page, quran_word_layouts};
+    use crate::schema::quran_words::dsl::quran_words;
+
+    let mut starts: HashMap<i32, SurahStart> = surahs
+        .iter()
+        .map(|surah| (*surah, SurahStart::default()))
+        .collect();
+
+    let juzs: Vec<(i32, i32, i32)> = quran_divisions
+        .filter(division_mushaf_id.eq(mushaf))
+        .filter(division_type.eq(DivisionType::Juz.to_string()))
+        .select((division_number, start_ayah_id, end_ayah_id))
+        .load(conn)?;
+
+    let ayah_ids: Vec<i32> = juzs
+        .iter()
+        .flat_map(|(_, start, end)| [*start, *end])
+        .collect();
+
+    let positions: HashMap<i32, AyahPosition> = quran_ayahs
+        .inner_join(quran_surahs)
+        .filter(ayah_id.eq_any(ayah_ids))
+        .select((ayah_id, (surah_number, ayah_number)))
+        .load::<(i32, AyahPosition)>(conn)?
+        .into_iter()
+        .collect();
+
+    let numbers: Vec<(i32, i32)> = quran_surahs
+        .filter(surah_id.eq_any(surahs))
+        .select((surah_id, surah_number))
+        .load(conn)?;
+
+    // The juz that contains the first ayah of the surah
+    for (surah, number) in numbers {
+        starts.get_mut(&surah).unwrap().start_juz = juzs
+            .iter()
+            .find(|(_, start, end)| (positions[start]..=positions[end]).contains(&(number, 1)))
+            .map(|(juz, _, _)| *juz);
+    }
+
+    let pages: Vec<(i32, Option<i32>)> = quran_word_layouts
+        .inner_join(quran_words.inner_join(quran_ayahs))
+        .filter(ayah_surah_id.eq_any(surahs))
+        .filter(ayah_number.eq(1))
+        .group_by(ayah_surah_id)
+        .select((ayah_surah_id, diesel::dsl::min(page)))
+        .load(conn)?;
+
+    for (surah, start_page) in pages {
+        starts.get_mut(&surah).unwrap().start_page = start_page;
+    }
+
+    Ok(starts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn surah(revelation_order: Option<i32>, rukus: Option<i32>) -> SimpleSurah {
+        SimpleSurah {
+            name: "الفاتحة".to_string(),
+            name_pronunciation: None,
+            name_translation_phrase: None,
+            name_transliteration: None,
+            period: Some(SurahPeriod::Makki),
+            revelation_order,
+            rukus,
+            number: None,
+            bismillah_status: true,
+            bismillah_as_first_ayah: true,
+            mushaf_uuid: Uuid::from_u128(1),
+        }
+    }
+
+    #[test]
+    fn test_check_surah_metadata() {
+        assert!(check_surah_metadata(&surah(None, None)).is_ok());
+        assert!(check_surah_metadata(&surah(Some(5), Some(1))).is_ok());
+        assert!(check_surah_metadata(&surah(Some(0), None)).is_err());
+        assert!(check_surah_metadata(&surah(None, Some(-1))).is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use super::SimpleSurah;
+use super::{check_surah_metadata, SimpleSurah};
 use crate::error::RouterErrorDetail;
 use crate::models::NewQuranSurah;
 use crate::{error::RouterError, DbPool};
@@ -15,11 +15,17 @@ pub async fn surah_add(
     use crate::schema::app_phrases::dsl::{app_phrases, phrase as phrase_text};
     use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
     use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
-    use crate::schema::quran_surahs::dsl::quran_surahs;
+    use crate::schema::quran_surahs::dsl::{
+        mushaf_id as surah_mushaf_id, number as surah_number, quran_surahs,
+    };
 
     let new_surah = new_surah.into_inner();
     let data = data.into_inner();
 
+    check_surah_metadata(&new_surah).map_err(|err| {
+        RouterError::from_predefined_with_detail("SURAH_METADATA_NOT_VALID", &err)
+    })?;
+
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
@@ -30,13 +36,6 @@ pub async fn surah_add(
             .select(mushaf_id)
             .get_result(&mut conn)?;
 
-        // Calculate amount of surahs in mushaf
-        let latest_surah_number: i64 = quran_surahs
-            .inner_join(quran_mushafs)
-            .filter(mushaf_id.eq(mushaf))
-            .count()
-            .get_result(&mut conn)?;
-
         let user: i32 = app_users
             .filter(user_acc_id.eq(data as i32))
             .select(user_id)
@@ -54,23 +53,57 @@ pub async fn surah_add(
             }
         }
 
-        // Add a new surah
-        NewQuranSurah {
-            creator_user_id: user,
-            name: new_surah.name,
-            period: new_surah.period,
-            number: (latest_surah_number + 1) as i32,
-            mushaf_id: mushaf,
-            bismillah_status: new_surah.bismillah_status,
-            bismillah_as_first_ayah: new_surah.bismillah_as_first_ayah,
-            name_pronunciation: new_surah.name_pronunciation,
-            name_translation_phrase: new_surah.name_translation_phrase,
-            name_transliteration: new_surah.name_transliteration,
-        }
-        .insert_into(quran_surahs)
-        .execute(&mut conn)?;
+        conn.transaction(|conn| {
+            // Calculate amount of surahs in mushaf
+            let surahs_count: i64 = quran_surahs
+                .filter(surah_mushaf_id.eq(mushaf))
+                .count()
+                .get_result(conn)?;
+
+            let number = match new_surah.number {
+                Some(number) if number >= 1 && number as i64 <= surahs_count + 1 => number,
+
+                Some(number) => {
+                    return Err(RouterError::from_predefined_with_detail(
+                        "SURAH_NUMBER_NOT_VALID",
+                        &format!(
+                            "surah number {} is not between 1 and {}",
+                            number,
+                            surahs_count + 1
+                        ),
+                    ))
+                }
+
+                None => surahs_count as i32 + 1,
+            };
+
+            // Shift the surahs after the new one
+            diesel::update(quran_surahs)
+                .filter(surah_mushaf_id.eq(mushaf))
+                .filter(surah_number.ge(number))
+                .set(surah_number.eq(surah_number + 1))
+                .execute(conn)?;
+
+            // Add a new surah
+            NewQuranSurah {
+                creator_user_id: user,
+                name: new_surah.name,
+                period: new_surah.period.map(|period| period.to_string()),
+                number,
+                mushaf_id: mushaf,
+                bismillah_status: new_surah.bismillah_status,
+                bismillah_as_first_ayah: new_surah.bismillah_as_first_ayah,
+                name_pronunciation: new_surah.name_pronunciation,
+                name_translation_phrase: new_surah.name_translation_phrase,
+                name_transliteration: new_surah.name_transliteration,
+                revelation_order: new_surah.revelation_order,
+                rukus: new_surah.rukus,
+            }
+            .insert_into(quran_surahs)
+            .execute(conn)?;
 
-        Ok("Added")
+            Ok("Added")
+        })
     })
     .await
     .unwrap()
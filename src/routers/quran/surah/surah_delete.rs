@@ -3,21 +3,34 @@ use actix_web::web;
 use diesel::prelude::*;
 use uuid::Uuid;
 
-/// Delete's the specific surah
+/// Delete's the specific surah, the surahs after it are renumbered
 pub async fn surah_delete(
     path: web::Path<Uuid>,
     pool: web::Data<DbPool>,
 ) -> Result<&'static str, RouterError> {
-    use crate::schema::quran_surahs::dsl::{quran_surahs, uuid as surah_uuid};
+    use crate::schema::quran_surahs::dsl::{
+        mushaf_id as surah_mushaf_id, number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
 
     let target_surah_uuid = path.into_inner();
 
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
-        diesel::delete(quran_surahs.filter(surah_uuid.eq(target_surah_uuid))).execute(&mut conn)?;
+        conn.transaction(|conn| {
+            let (mushaf, number): (i32, i32) =
+                diesel::delete(quran_surahs.filter(surah_uuid.eq(target_surah_uuid)))
+                    .returning((surah_mushaf_id, surah_number))
+                    .get_result(conn)?;
 
-        Ok("Deleted")
+            diesel::update(quran_surahs)
+                .filter(surah_mushaf_id.eq(mushaf))
+                .filter(surah_number.gt(number))
+                .set(surah_number.eq(surah_number - 1))
+                .execute(conn)?;
+
+            Ok("Deleted")
+        })
     })
     .await
     .unwrap()
@@ -4,9 +4,12 @@ use actix_web::web;
 use diesel::prelude::*;
 use uuid::Uuid;
 
-use super::SimpleSurah;
+use super::{check_surah_metadata, SimpleSurah};
 
 /// Update's single surah
+///
+/// When the number changes the surah is moved, and the
+/// other surahs of the mushaf are renumbered
 pub async fn surah_edit(
     path: web::Path<Uuid>,
     new_surah: web::Json<SimpleSurah>,
@@ -14,14 +17,18 @@ pub async fn surah_edit(
 ) -> Result<&'static str, RouterError> {
     use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
     use crate::schema::quran_surahs::dsl::{
-        bismillah_as_first_ayah, bismillah_status, mushaf_id as surah_mushaf_id, name,
-        name_pronunciation, name_translation_phrase, name_transliteration, number, period,
-        quran_surahs, uuid as surah_uuid,
+        bismillah_as_first_ayah, bismillah_status, id as surah_id, mushaf_id as surah_mushaf_id,
+        name, name_pronunciation, name_translation_phrase, name_transliteration, number, period,
+        quran_surahs, revelation_order, rukus, uuid as surah_uuid,
     };
 
     let new_surah = new_surah.into_inner();
     let target_surah_uuid = path.into_inner();
 
+    check_surah_metadata(&new_surah).map_err(|err| {
+        RouterError::from_predefined_with_detail("SURAH_METADATA_NOT_VALID", &err)
+    })?;
+
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
@@ -32,21 +39,72 @@ pub async fn surah_edit(
             .select(mushaf_id)
             .get_result(&mut conn)?;
 
-        diesel::update(quran_surahs.filter(surah_uuid.eq(target_surah_uuid)))
-            .set((
-                number.eq(new_surah.number),
-                surah_mushaf_id.eq(mushaf),
-                name.eq(new_surah.name),
-                bismillah_status.eq(new_surah.bismillah_status),
-                bismillah_as_first_ayah.eq(new_surah.bismillah_as_first_ayah),
-                period.eq(new_surah.period),
-                name_pronunciation.eq(new_surah.name_pronunciation),
-                name_translation_phrase.eq(new_surah.name_translation_phrase),
-                name_transliteration.eq(new_surah.name_transliteration),
-            ))
-            .execute(&mut conn)?;
-
-        Ok("Edited")
+        conn.transaction(|conn| {
+            // Numbers are unique per mushaf, they are checked
+            // when the transaction is committed
+            diesel::sql_query("SET CONSTRAINTS quran_surahs_number DEFERRED").execute(conn)?;
+
+            let (target_id, old_mushaf, old_number): (i32, i32, i32) = quran_surahs
+                .filter(surah_uuid.eq(target_surah_uuid))
+                .select((surah_id, surah_mushaf_id, number))
+                .get_result(conn)?;
+
+            let other_surahs: i64 = quran_surahs
+                .filter(surah_mushaf_id.eq(mushaf))
+                .filter(surah_id.ne(target_id))
+                .count()
+                .get_result(conn)?;
+
+            let new_number = match new_surah.number {
+                Some(new_number) => new_number,
+                None if mushaf == old_mushaf => old_number,
+                None => other_surahs as i32 + 1,
+            };
+
+            if new_number < 1 || new_number as i64 > other_surahs + 1 {
+                return Err(RouterError::from_predefined_with_detail(
+                    "SURAH_NUMBER_NOT_VALID",
+                    &format!(
+                        "surah number {} is not between 1 and {}",
+                        new_number,
+                        other_surahs + 1
+                    ),
+                ));
+            }
+
+            // Close the gap of the old position and open the new one
+            diesel::update(quran_surahs)
+                .filter(surah_mushaf_id.eq(old_mushaf))
+                .filter(surah_id.ne(target_id))
+                .filter(number.gt(old_number))
+                .set(number.eq(number - 1))
+                .execute(conn)?;
+
+            diesel::update(quran_surahs)
+                .filter(surah_mushaf_id.eq(mushaf))
+                .filter(surah_id.ne(target_id))
+                .filter(number.ge(new_number))
+                .set(number.eq(number + 1))
+                .execute(conn)?;
+
+            diesel::update(quran_surahs.filter(surah_id.eq(target_id)))
+                .set((
+                    number.eq(new_number),
+                    surah_mushaf_id.eq(mushaf),
+                    name.eq(new_surah.name),
+                    bismillah_status.eq(new_surah.bismillah_status),
+                    bismillah_as_first_ayah.eq(new_surah.bismillah_as_first_ayah),
+                    period.eq(new_surah.period.map(|p| p.to_string())),
+                    revelation_order.eq(new_surah.revelation_order),
+                    rukus.eq(new_surah.rukus),
+                    name_pronunciation.eq(new_surah.name_pronunciation),
+                    name_translation_phrase.eq(new_surah.name_translation_phrase),
+                    name_transliteration.eq(new_surah.name_transliteration),
+                ))
+                .execute(conn)?;
+
+            Ok("Edited")
+        })
     })
     .await
     .unwrap()
@@ -1,4 +1,4 @@
-use super::{SurahListQuery, SurahListResponse};
+use super::{surah_starts, SurahListQuery, SurahListResponse};
 use crate::error::RouterErrorDetailBuilder;
 use crate::filter::Filter;
 use crate::models::{QuranAyah, QuranMushaf, QuranSurah};
@@ -58,6 +58,9 @@ pub async fn surah_list(
             })
             .collect::<Vec<i64>>();
 
+        let surah_ids: Vec<i32> = surahs.iter().map(|surah| surah.id).collect();
+        let mut starts = surah_starts(&mut conn, mushaf.id, &surah_ids)?;
+
         // now iter over the surahs and bind it with
         // number_of_ayahs
         let surahs = surahs
@@ -82,6 +85,7 @@ pub async fn surah_list(
                 };
 
                 SurahListResponse {
+                    start: starts.remove(&surah.id).unwrap_or_default(),
                     uuid: surah.uuid,
                     names: vec![SurahName {
                         arabic: surah.name,
@@ -93,6 +97,8 @@ pub async fn surah_list(
                     number: surah.number,
                     period: surah.period,
                     number_of_ayahs,
+                    revelation_order: surah.revelation_order,
+                    rukus: surah.rukus,
                 }
             })
             .collect::<Vec<SurahListResponse>>();
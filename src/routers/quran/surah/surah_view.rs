@@ -1,10 +1,19 @@
-use super::{Format, GetSurahQuery, QuranResponseData, SimpleAyah, SingleSurahResponse};
+use super::{
+    surah_starts, Format, GetSurahQuery, QuranResponseData, SimpleAyah, SingleSurahResponse,
+};
+use crate::checksum::surah_checksums;
 use crate::models::{QuranAyah, QuranMushaf, QuranSurah};
 use crate::routers::multip;
+use crate::routers::quran::ayah::marked_ayahs;
+use crate::routers::quran::word::{
+    render_words, word_morphologies, word_tajweeds, word_translations, WaqfMark,
+};
+use crate::routers::recitation::ayah_audios;
 use crate::{error::RouterError, DbPool};
 use crate::{AyahTy, SingleSurahMushaf, SurahName};
 use actix_web::web;
 use diesel::prelude::*;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// View Surah
@@ -17,11 +26,13 @@ pub async fn surah_view(
         app_phrase_translations, language as p_t_lang, text as p_t_text,
     };
     use crate::schema::app_phrases::dsl::{app_phrases, phrase as p_phrase};
-    use crate::schema::quran_ayahs::dsl::quran_ayahs;
+    use crate::schema::quran_ayahs::dsl::{ayah_number, id as ayah_id, quran_ayahs};
     use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs};
     use crate::schema::quran_surahs::dsl::quran_surahs;
     use crate::schema::quran_surahs::dsl::uuid as surah_uuid;
-    use crate::schema::quran_words::dsl::{quran_words, word as q_word};
+    use crate::schema::quran_words::dsl::{
+        id as word_id, quran_words, waqf, word as q_word, word_number,
+    };
 
     let query = query.into_inner();
     let requested_surah_uuid = path.into_inner();
@@ -29,11 +40,71 @@ pub async fn surah_view(
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
-        let result = quran_surahs
+        let mut ayahs_query = quran_surahs
             .filter(surah_uuid.eq(requested_surah_uuid))
             .inner_join(quran_ayahs.inner_join(quran_words))
-            .select((QuranAyah::as_select(), q_word))
-            .load::<(QuranAyah, String)>(&mut conn)?;
+            .order((ayah_number.asc(), word_number.asc()))
+            .select((QuranAyah::as_select(), (word_id, q_word, waqf)))
+            .into_boxed();
+
+        if let Some(marker) = query.marker {
+            ayahs_query = ayahs_query.filter(ayah_id.eq_any(marked_ayahs(marker)));
+        }
+
+        let mut result =
+            ayahs_query.load::<(QuranAyah, (i32, String, Option<String>))>(&mut conn)?;
+
+        // The waqf signs are in the text unless they are not requested
+        render_words(
+            &mut conn,
+            query.script,
+            query.waqf.unwrap_or(true),
+            result.iter_mut().map(|(_, (id, word, word_waqf))| {
+                (*id, word, WaqfMark::from_option_string(word_waqf.clone()))
+            }),
+        )?;
+
+        let mut waqfs: HashMap<i32, WaqfMark> = HashMap::new();
+
+        let result: Vec<(QuranAyah, (i32, String))> = result
+            .into_iter()
+            .map(|(ayah, (id, word, word_waqf))| {
+                if let Some(mark) = WaqfMark::from_option_string(word_waqf) {
+                    waqfs.insert(id, mark);
+                }
+
+                (ayah, (id, word))
+            })
+            .collect();
+
+        let word_ids: Vec<i32> = match query.format {
+            Format::Word => result.iter().map(|(_, (id, _))| *id).collect(),
+            Format::Text => vec![],
+        };
+
+        // Only the word format has the morphology and word translations
+        let mut morphologies = word_morphologies(&mut conn, &word_ids)?;
+
+        let mut translations = match query.word_lang {
+            Some(ref lang) => Some(word_translations(&mut conn, &word_ids, lang)?),
+            None => None,
+        };
+
+        let mut tajweeds = if query.tajweed {
+            Some(word_tajweeds(&mut conn, &word_ids)?)
+        } else {
+            None
+        };
+
+        let mut audios = match query.recitation {
+            Some(recitation) => {
+                let mut ayah_ids: Vec<i32> = result.iter().map(|(ayah, _)| ayah.id).collect();
+                ayah_ids.dedup();
+
+                ayah_audios(&mut conn, recitation, &ayah_ids)?
+            }
+            None => HashMap::new(),
+        };
 
         let ayahs_as_map = multip(result, |ayah| SimpleAyah {
             number: ayah.ayah_number as u32,
@@ -44,11 +115,53 @@ pub async fn surah_view(
         let final_ayahs = ayahs_as_map
             .into_iter()
             .map(|(ayah, words)| match query.format {
-                Format::Text => AyahTy::Text(crate::AyahWithText {
+                Format::Text => {
+                    let text = words
+                        .into_iter()
+                        .map(|(_, word)| word)
+                        .collect::<Vec<String>>()
+                        .join(" ");
+
+                    AyahTy::Text(crate::AyahWithText {
+                        audio: audios.remove(&ayah.uuid),
+                        ayah,
+                        transliteration: query.translit.map(|kind| kind.transliterate(&text)),
+                        text,
+                    })
+                }
+                Format::Word => AyahTy::Words(crate::AyahWithWords {
+                    audio: audios.remove(&ayah.uuid),
                     ayah,
-                    text: words.join(" "),
+                    morphology: Some(
+                        words
+                            .iter()
+                            .map(|(id, _)| morphologies.remove(id))
+                            .collect(),
+                    ),
+                    translations: translations.as_mut().map(|translations| {
+                        words
+                            .iter()
+                            .map(|(id, _)| translations.remove(id))
+                            .collect()
+                    }),
+                    tajweed: tajweeds.as_mut().map(|tajweeds| {
+                        words
+                            .iter()
+                            .map(|(id, _)| tajweeds.remove(id).unwrap_or_default())
+                            .collect()
+                    }),
+                    waqf: Some(words.iter().map(|(id, _)| waqfs.remove(id)).collect()),
+                    transliteration: query.translit.map(|kind| {
+                        kind.transliterate(
+                            &words
+                                .iter()
+                                .map(|(_, word)| word.as_str())
+                                .collect::<Vec<&str>>()
+                                .join(" "),
+                        )
+                    }),
+                    words: words.into_iter().map(|(_, word)| word).collect(),
                 }),
-                Format::Word => AyahTy::Words(crate::AyahWithWords { ayah, words }),
             })
             .collect::<Vec<AyahTy>>();
 
@@ -68,6 +181,21 @@ pub async fn surah_view(
             mushaf.bismillah_text.clone() // this is Option<String>
         };
 
+        // Not the count of final_ayahs, the marker filter is not on the surah
+        let number_of_ayahs: i64 = QuranAyah::belonging_to(&surah)
+            .count()
+            .get_result(&mut conn)?;
+
+        let checksum = if query.checksum {
+            surah_checksums(&mut conn, &[surah.id])?.remove(&surah.id)
+        } else {
+            None
+        };
+
+        let start = surah_starts(&mut conn, mushaf.id, &[surah.id])?
+            .remove(&surah.id)
+            .unwrap_or_default();
+
         let translation = if let Some(ref phrase) = surah.name_translation_phrase {
             let mut p = app_phrases.left_join(app_phrase_translations).into_boxed();
 
@@ -100,7 +228,11 @@ pub async fn surah_view(
                 bismillah_status: surah.bismillah_status,
                 bismillah_as_first_ayah: surah.bismillah_as_first_ayah,
                 bismillah_text: mushaf_bismillah_text,
-                number_of_ayahs: final_ayahs.len() as i64,
+                number_of_ayahs,
+                revelation_order: surah.revelation_order,
+                rukus: surah.rukus,
+                start,
+                checksum,
             },
             ayahs: final_ayahs,
         }))
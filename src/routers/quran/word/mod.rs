@@ -1,11 +1,432 @@
 pub mod word_delete;
 pub mod word_edit;
+pub mod word_translation_delete;
+pub mod word_translation_modify;
+pub mod word_translation_view;
 pub mod word_view;
 pub mod word_add;
 
-use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::models::QuranWordMorphology;
 
 #[derive(Deserialize)]
 pub struct SimpleWord {
     pub word: String,
 }
+
+/// The query for /word/{uuid}/translation
+/// example /word/{uuid}/translation?language=en
+#[derive(Deserialize)]
+pub struct WordTranslationQuery {
+    pub language: String,
+}
+
+/// Translation (gloss) of a single word in a language
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WordTranslation {
+    pub text: String,
+    pub transliteration: Option<String>,
+}
+
+/// Script variants of a word, the word itself is kept
+/// in the script of its mushaf
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WordScript {
+    /// Uthmani script of the Hafs riwayah
+    Uthmani,
+
+    /// Simple modern spelling
+    Imlaei,
+    Indopak,
+
+    /// Plain letters without any marks, used for searching
+    Search,
+}
+
+impl Display for WordScript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uthmani => write!(f, "uthmani"),
+            Self::Imlaei => write!(f, "imlaei"),
+            Self::Indopak => write!(f, "indopak"),
+            Self::Search => write!(f, "search"),
+        }
+    }
+}
+
+/// Waqf (pause) sign that comes after the word
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum WaqfMark {
+    /// Continuing is preferred (U+06D6)
+    Sala,
+
+    /// Stopping is preferred (U+06D7)
+    Qala,
+
+    /// Stopping is necessary (U+06D8)
+    Lazim,
+
+    /// Stopping is not allowed (U+06D9)
+    La,
+
+    /// Stopping is permissible (U+06DA)
+    Jaiz,
+
+    /// Stop at only one of the two signs (U+06DB)
+    Muanaqah,
+}
+
+impl Display for WaqfMark {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sala => write!(f, "sala"),
+            Self::Qala => write!(f, "qala"),
+            Self::Lazim => write!(f, "lazim"),
+            Self::La => write!(f, "la"),
+            Self::Jaiz => write!(f, "jaiz"),
+            Self::Muanaqah => write!(f, "muanaqah"),
+        }
+    }
+}
+
+impl WaqfMark {
+    pub fn from_option_string(value: Option<String>) -> Option<Self> {
+        let value = value?;
+
+        match value.as_str() {
+            "sala" => Some(Self::Sala),
+            "qala" => Some(Self::Qala),
+            "lazim" => Some(Self::Lazim),
+            "la" => Some(Self::La),
+            "jaiz" => Some(Self::Jaiz),
+            "muanaqah" => Some(Self::Muanaqah),
+
+            _ => None,
+        }
+    }
+
+    pub fn from_sign(sign: char) -> Option<Self> {
+        match sign {
+            '\u{06D6}' => Some(Self::Sala),
+            '\u{06D7}' => Some(Self::Qala),
+            '\u{06D8}' => Some(Self::Lazim),
+            '\u{06D9}' => Some(Self::La),
+            '\u{06DA}' => Some(Self::Jaiz),
+            '\u{06DB}' => Some(Self::Muanaqah),
+
+            _ => None,
+        }
+    }
+
+    pub fn sign(&self) -> char {
+        match self {
+            Self::Sala => '\u{06D6}',
+            Self::Qala => '\u{06D7}',
+            Self::Lazim => '\u{06D8}',
+            Self::La => '\u{06D9}',
+            Self::Jaiz => '\u{06DA}',
+            Self::Muanaqah => '\u{06DB}',
+        }
+    }
+}
+
+/// A word without its waqf sign, and the sign
+pub type WaqfWord = (String, Option<WaqfMark>);
+
+/// Removes the waqf signs of the word, returns the word and its last sign
+pub fn split_waqf(word: &str) -> WaqfWord {
+    let mark = word.chars().filter_map(WaqfMark::from_sign).next_back();
+    let text = word
+        .chars()
+        .filter(|c| WaqfMark::from_sign(*c).is_none())
+        .collect();
+
+    (text, mark)
+}
+
+/// Separates the waqf signs of the words
+///
+/// A word of only waqf signs (like the tanzil uthmani text) is
+/// the sign of the word before it
+pub fn separate_waqf<S: AsRef<str>>(words: impl IntoIterator<Item = S>) -> Vec<WaqfWord> {
+    let mut result: Vec<WaqfWord> = vec![];
+
+    for word in words {
+        let (text, mark) = split_waqf(word.as_ref());
+
+        match result.last_mut() {
+            Some(last) if text.is_empty() && mark.is_some() => last.1 = mark,
+            _ => result.push((text, mark)),
+        }
+    }
+
+    result
+}
+
+/// Checks that the word is not only a waqf sign,
+/// the sign comes after a word
+pub fn check_waqf_word(word: &WaqfWord) -> Result<(), String> {
+    match word {
+        (text, Some(_)) if text.is_empty() => Err("only a waqf sign".to_string()),
+        _ => Ok(()),
+    }
+}
+
+/// The word with its waqf sign at the end
+pub fn with_waqf(word: String, mark: Option<WaqfMark>) -> String {
+    match mark {
+        Some(mark) => format!("{}{}", word, mark.sign()),
+        None => word,
+    }
+}
+
+/// A prefix, stem or suffix of the word
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WordSegment {
+    pub form: String,
+    pub tag: String,
+    pub features: String,
+}
+
+/// Grammatical analysis of a word
+///
+/// Tags and features are the Quranic Arabic Corpus ones,
+/// person is 1, 2 or 3, gender M or F, number S, D or P
+/// and case NOM, ACC or GEN
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WordMorphology {
+    pub root: Option<String>,
+    pub lemma: Option<String>,
+    pub part_of_speech: String,
+    pub person: Option<String>,
+    pub gender: Option<String>,
+    pub number: Option<String>,
+    pub case: Option<String>,
+    pub segments: Vec<WordSegment>,
+}
+
+/// A tajweed rule on the characters of a word, the end is not included
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WordTajweed {
+    pub rule: String,
+    pub start: i32,
+    pub end: i32,
+}
+
+/// Returns the tajweed annotations of the words in order, by word id
+pub fn word_tajweeds(
+    conn: &mut PgConnection,
+    words: &[i32],
+) -> QueryResult<HashMap<i32, Vec<WordTajweed>>> {
+    use crate::schema::quran_word_tajweeds::dsl::{
+        end_index, quran_word_tajweeds, rule, start_index, word_id,
+    };
+
+    let mut result: HashMap<i32, Vec<WordTajweed>> = HashMap::new();
+
+    for (id, tajweed_rule, start, end) in quran_word_tajweeds
+        .filter(word_id.eq_any(words))
+        .order(start_index.asc())
+        .select((word_id, rule, start_index, end_index))
+        .get_results::<(i32, String, i32, i32)>(conn)?
+    {
+        result.entry(id).or_default().push(WordTajweed {
+            rule: tajweed_rule,
+            start,
+            end,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Returns the morphology of the words that have one, by word id
+pub fn word_morphologies(
+    conn: &mut PgConnection,
+    words: &[i32],
+) -> QueryResult<HashMap<i32, WordMorphology>> {
+    use crate::schema::quran_word_morphologies::dsl::{quran_word_morphologies, word_id};
+    use crate::schema::quran_word_segments::dsl::{
+        features, form, quran_word_segments, segment_number, tag,
+    };
+
+    let morphologies: Vec<QuranWordMorphology> = quran_word_morphologies
+        .filter(word_id.eq_any(words))
+        .select(QuranWordMorphology::as_select())
+        .get_results(conn)?;
+
+    let segments: Vec<(i32, WordSegment)> = quran_word_segments
+        .inner_join(quran_word_morphologies)
+        .filter(word_id.eq_any(words))
+        .order((word_id.asc(), segment_number.asc()))
+        .select((word_id, (form, tag, features)))
+        .get_results::<(i32, (String, String, String))>(conn)?
+        .into_iter()
+        .map(|(id, (s_form, s_tag, s_features))| {
+            (
+                id,
+                WordSegment {
+                    form: s_form,
+                    tag: s_tag,
+                    features: s_features,
+                },
+            )
+        })
+        .collect();
+
+    let mut result: HashMap<i32, WordMorphology> = morphologies
+        .into_iter()
+        .map(|morphology| {
+            (
+                morphology.word_id,
+                WordMorphology {
+                    root: morphology.root,
+                    lemma: morphology.lemma,
+                    part_of_speech: morphology.part_of_speech,
+                    person: morphology.person,
+                    gender: morphology.gender,
+                    number: morphology.grammatical_number,
+                    case: morphology.grammatical_case,
+                    segments: vec![],
+                },
+            )
+        })
+        .collect();
+
+    for (id, segment) in segments {
+        if let Some(morphology) = result.get_mut(&id) {
+            morphology.segments.push(segment);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Replaces the words (by word id) with their variant in the script,
+/// words without the variant are kept as they are
+///
+/// Returns the ids of the replaced words
+pub fn apply_word_script<'a>(
+    conn: &mut PgConnection,
+    requested_script: WordScript,
+    words: impl IntoIterator<Item = (i32, &'a mut String)>,
+) -> QueryResult<HashSet<i32>> {
+    use crate::schema::quran_word_scripts::dsl::{quran_word_scripts, script, text, word_id};
+
+    let mut words: Vec<(i32, &mut String)> = words.into_iter().collect();
+    let ids: Vec<i32> = words.iter().map(|(id, _)| *id).collect();
+
+    let mut variants: HashMap<i32, String> = quran_word_scripts
+        .filter(word_id.eq_any(ids))
+        .filter(script.eq(requested_script.to_string()))
+        .select((word_id, text))
+        .get_results::<(i32, String)>(conn)?
+        .into_iter()
+        .collect();
+
+    let mut replaced = HashSet::new();
+
+    for (id, word) in words.iter_mut() {
+        if let Some(variant) = variants.remove(id) {
+            **word = variant;
+            replaced.insert(*id);
+        }
+    }
+
+    Ok(replaced)
+}
+
+/// Prepares the words (by word id) for showing, replaces them with their
+/// variant in the script when requested and adds the waqf signs
+///
+/// The waqf signs are of the mushaf script, so the replaced words don't get them
+pub fn render_words<'a>(
+    conn: &mut PgConnection,
+    requested_script: Option<WordScript>,
+    render_waqf: bool,
+    words: impl IntoIterator<Item = (i32, &'a mut String, Option<WaqfMark>)>,
+) -> QueryResult<()> {
+    let mut words: Vec<(i32, &mut String, Option<WaqfMark>)> = words.into_iter().collect();
+
+    let scripted = match requested_script {
+        Some(requested_script) => apply_word_script(
+            conn,
+            requested_script,
+            words.iter_mut().map(|(id, word, _)| (*id, &mut **word)),
+        )?,
+        None => HashSet::new(),
+    };
+
+    if render_waqf {
+        for (id, word, mark) in words {
+            if let Some(mark) = mark.filter(|_| !scripted.contains(&id)) {
+                word.push(mark.sign());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the translations of the words in the language, by word id
+pub fn word_translations(
+    conn: &mut PgConnection,
+    words: &[i32],
+    translation_language: &str,
+) -> QueryResult<HashMap<i32, WordTranslation>> {
+    use crate::schema::quran_word_translations::dsl::{
+        language, quran_word_translations, text, transliteration, word_id,
+    };
+
+    Ok(quran_word_translations
+        .filter(word_id.eq_any(words))
+        .filter(language.eq(translation_language))
+        .select((word_id, text, transliteration))
+        .get_results::<(i32, String, Option<String>)>(conn)?
+        .into_iter()
+        .map(|(id, w_text, w_transliteration)| {
+            (
+                id,
+                WordTranslation {
+                    text: w_text,
+                    transliteration: w_transliteration,
+                },
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_separate_waqf() {
+        assert_eq!(
+            split_waqf("رَيْبَۛ"),
+            ("رَيْبَ".to_string(), Some(WaqfMark::Muanaqah))
+        );
+
+        assert_eq!(
+            separate_waqf(["لَا", "رَيْبَ", "ۛ", "فِيهِۛ", "هُدًى"]),
+            vec![
+                ("لَا".to_string(), None),
+                ("رَيْبَ".to_string(), Some(WaqfMark::Muanaqah)),
+                ("فِيهِ".to_string(), Some(WaqfMark::Muanaqah)),
+                ("هُدًى".to_string(), None),
+            ]
+        );
+
+        assert_eq!(with_waqf("فِيهِ".to_string(), Some(WaqfMark::Jaiz)), "فِيهِۚ");
+
+        assert!(check_waqf_word(&split_waqf("رَيْبَۛ")).is_ok());
+        assert!(check_waqf_word(&separate_waqf(["ۛ", "فِيهِ"])[0]).is_err());
+    }
+}
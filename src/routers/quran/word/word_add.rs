@@ -3,25 +3,30 @@ use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::{
-    error::RouterError,
-    models::NewQuranWord,
-    DbPool,
-};
+use crate::{error::RouterError, models::NewQuranWord, DbPool};
+
+use super::{check_waqf_word, split_waqf};
 
 #[derive(Deserialize, Serialize)]
 pub struct ReqWord {
     ayah_uuid: Uuid,
     word: String,
+
+    /// Position of the new word in the ayah, the word is
+    /// added to the end of the ayah if not given
+    word_number: Option<i32>,
 }
 
+/// Add's a word to the ayah, the words after
+/// the given position are shifted
 pub async fn word_add(
     pool: web::Data<DbPool>,
     user_id: web::ReqData<u32>,
     new_word: web::Json<ReqWord>,
 ) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as app_user_id};
     use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs, uuid as ayah_uid};
-    use crate::schema::quran_words::dsl::quran_words;
+    use crate::schema::quran_words::dsl::{ayah_id as word_ayah_id, quran_words, word_number};
 
     let user_id = user_id.into_inner();
     let new_word = new_word.into_inner();
@@ -29,20 +34,65 @@ pub async fn word_add(
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(user_id as i32))
+            .select(app_user_id)
+            .get_result(&mut conn)?;
+
         let target_ayah_id: i32 = quran_ayahs
             .filter(ayah_uid.eq(new_word.ayah_uuid))
             .select(ayah_id)
             .get_result(&mut conn)?;
 
-        NewQuranWord {
-            ayah_id: target_ayah_id,
-            word: new_word.word.as_str(),
-            creator_user_id: user_id as i32,
-        }
-        .insert_into(quran_words)
-        .execute(&mut conn)?;
+        // The waqf sign comes with the word
+        let split_word = split_waqf(&new_word.word);
+
+        check_waqf_word(&split_word)
+            .map_err(|err| RouterError::from_predefined_with_detail("WORD_NOT_VALID", &err))?;
+
+        conn.transaction(|conn| {
+            let words_count: i64 = quran_words
+                .filter(word_ayah_id.eq(target_ayah_id))
+                .count()
+                .get_result(conn)?;
+
+            let position = match new_word.word_number {
+                Some(number) if number >= 1 && number as i64 <= words_count + 1 => number,
+
+                Some(number) => {
+                    return Err(RouterError::from_predefined_with_detail(
+                        "WORD_NUMBER_NOT_VALID",
+                        &format!(
+                            "word number {} is not between 1 and {}",
+                            number,
+                            words_count + 1
+                        ),
+                    ))
+                }
+
+                None => words_count as i32 + 1,
+            };
+
+            diesel::update(quran_words)
+                .filter(word_ayah_id.eq(target_ayah_id))
+                .filter(word_number.ge(position))
+                .set(word_number.eq(word_number + 1))
+                .execute(conn)?;
+
+            let (text, waqf) = split_word;
+
+            NewQuranWord {
+                ayah_id: target_ayah_id,
+                word: &text,
+                creator_user_id: user,
+                word_number: position,
+                waqf: waqf.map(|waqf| waqf.to_string()),
+            }
+            .insert_into(quran_words)
+            .execute(conn)?;
 
-        Ok("added")
+            Ok("added")
+        })
     })
     .await
     .unwrap()
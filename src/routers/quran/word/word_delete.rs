@@ -4,21 +4,34 @@ use ::uuid::Uuid;
 use actix_web::web;
 use diesel::prelude::*;
 
-/// Delete's a single word
+/// Delete's a single word, the words after it are shifted back
 pub async fn word_delete(
     path: web::Path<Uuid>,
     pool: web::Data<DbPool>,
 ) -> Result<&'static str, RouterError> {
-    use crate::schema::quran_words::dsl::{quran_words, uuid as word_uuid};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, quran_words, uuid as word_uuid, word_number,
+    };
 
     let target_word_uuid = path.into_inner();
 
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
-        diesel::delete(quran_words.filter(word_uuid.eq(target_word_uuid))).execute(&mut conn)?;
+        conn.transaction(|conn| {
+            let (ayah, number): (i32, i32) =
+                diesel::delete(quran_words.filter(word_uuid.eq(target_word_uuid)))
+                    .returning((word_ayah_id, word_number))
+                    .get_result(conn)?;
 
-        Ok("Deleted")
+            diesel::update(quran_words)
+                .filter(word_ayah_id.eq(ayah))
+                .filter(word_number.gt(number))
+                .set(word_number.eq(word_number - 1))
+                .execute(conn)?;
+
+            Ok("Deleted")
+        })
     })
     .await
     .unwrap()
@@ -4,7 +4,7 @@ use actix_web::web;
 use diesel::prelude::*;
 use uuid::Uuid;
 
-use super::SimpleWord;
+use super::{check_waqf_word, split_waqf, SimpleWord};
 
 /// Update's single quran_word
 pub async fn word_edit(
@@ -12,7 +12,9 @@ pub async fn word_edit(
     new_word: web::Json<SimpleWord>,
     pool: web::Data<DbPool>,
 ) -> Result<&'static str, RouterError> {
-    use crate::schema::quran_words::dsl::{quran_words, uuid as word_uuid, word as word_content};
+    use crate::schema::quran_words::dsl::{
+        quran_words, uuid as word_uuid, waqf as word_waqf, word as word_content,
+    };
 
     let new_word = new_word.into_inner();
     let target_word_uuid = path.into_inner();
@@ -20,8 +22,19 @@ pub async fn word_edit(
     web::block(move || {
         let mut conn = pool.get().unwrap();
 
+        // The waqf sign comes with the word
+        let split_word = split_waqf(&new_word.word);
+
+        check_waqf_word(&split_word)
+            .map_err(|err| RouterError::from_predefined_with_detail("WORD_NOT_VALID", &err))?;
+
+        let (text, waqf) = split_word;
+
         diesel::update(quran_words.filter(word_uuid.eq(target_word_uuid)))
-            .set(word_content.eq(new_word.word))
+            .set((
+                word_content.eq(text),
+                word_waqf.eq(waqf.map(|waqf| waqf.to_string())),
+            ))
             .execute(&mut conn)?;
 
         Ok("Edited")
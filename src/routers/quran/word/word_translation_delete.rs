@@ -0,0 +1,42 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::WordTranslationQuery;
+
+/// Delete the translation of a word in the language
+pub async fn word_translation_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    query: web::Query<WordTranslationQuery>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_word_translations::dsl::{
+        language, quran_word_translations, word_id as translation_word_id,
+    };
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words, uuid as word_uuid};
+
+    let target_word_uuid = path.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let word: i32 = quran_words
+            .filter(word_uuid.eq(target_word_uuid))
+            .select(word_id)
+            .get_result(&mut conn)?;
+
+        diesel::delete(
+            quran_word_translations
+                .filter(translation_word_id.eq(word))
+                .filter(language.eq(query.language)),
+        )
+        .execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
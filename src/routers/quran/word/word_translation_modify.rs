@@ -0,0 +1,80 @@
+use crate::error::RouterError;
+use crate::models::NewQuranWordTranslation;
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::{dsl::exists, prelude::*, select};
+
+use super::{WordTranslation, WordTranslationQuery};
+
+/// Modify the translation of a word,
+///
+/// If the word has a translation in the language update it,
+/// otherwise add.
+pub async fn word_translation_modify(
+    path: web::Path<Uuid>,
+    new_translation: web::Json<WordTranslation>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+    query: web::Query<WordTranslationQuery>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_word_translations::dsl::{
+        language, quran_word_translations, text as translation_text, transliteration,
+        word_id as translation_word_id,
+    };
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words, uuid as word_uuid};
+
+    let target_word_uuid = path.into_inner();
+    let new_translation = new_translation.into_inner();
+    let data = data.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let word: i32 = quran_words
+            .filter(word_uuid.eq(target_word_uuid))
+            .select(word_id)
+            .get_result(&mut conn)?;
+
+        let translation_exists: bool = select(exists(
+            quran_word_translations
+                .filter(translation_word_id.eq(word))
+                .filter(language.eq(&query.language)),
+        ))
+        .get_result(&mut conn)?;
+
+        if translation_exists {
+            diesel::update(quran_word_translations)
+                .filter(translation_word_id.eq(word))
+                .filter(language.eq(&query.language))
+                .set((
+                    translation_text.eq(new_translation.text),
+                    transliteration.eq(new_translation.transliteration),
+                ))
+                .execute(&mut conn)?;
+
+            Ok("Updated")
+        } else {
+            let user: i32 = app_users
+                .filter(user_acc_id.eq(data as i32))
+                .select(user_id)
+                .get_result(&mut conn)?;
+
+            NewQuranWordTranslation {
+                creator_user_id: user,
+                word_id: word,
+                language: &query.language,
+                text: &new_translation.text,
+                transliteration: new_translation.transliteration.as_deref(),
+            }
+            .insert_into(quran_word_translations)
+            .execute(&mut conn)?;
+
+            Ok("Added")
+        }
+    })
+    .await
+    .unwrap()
+}
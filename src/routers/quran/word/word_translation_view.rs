@@ -0,0 +1,42 @@
+use crate::error::RouterError;
+use crate::models::QuranWordTranslation;
+use crate::DbPool;
+use ::uuid::Uuid;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::WordTranslationQuery;
+
+/// Return's the translation of a single word
+pub async fn word_translation_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    query: web::Query<WordTranslationQuery>,
+) -> Result<web::Json<QuranWordTranslation>, RouterError> {
+    use crate::schema::quran_word_translations::dsl::{
+        language, quran_word_translations, word_id as translation_word_id,
+    };
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words, uuid as word_uuid};
+
+    let requested_word_uuid = path.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let word: i32 = quran_words
+            .filter(word_uuid.eq(requested_word_uuid))
+            .select(word_id)
+            .get_result(&mut conn)?;
+
+        let translation: QuranWordTranslation = quran_word_translations
+            .filter(translation_word_id.eq(word))
+            .filter(language.eq(query.language))
+            .select(QuranWordTranslation::as_select())
+            .get_result(&mut conn)?;
+
+        Ok(web::Json(translation))
+    })
+    .await
+    .unwrap()
+}
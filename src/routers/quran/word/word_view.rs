@@ -1,5 +1,6 @@
 use crate::error::RouterError;
 use crate::models::QuranWord;
+use crate::routers::quran::word::{with_waqf, WaqfMark};
 use crate::DbPool;
 use ::uuid::Uuid;
 use actix_web::web;
@@ -18,10 +19,15 @@ pub async fn word_view(
         let mut conn = pool.get().unwrap();
 
         // Get the single word from the database
-        let quran_word: QuranWord = quran_words
+        let mut quran_word: QuranWord = quran_words
             .filter(word_uuid.eq(requested_word_uuid))
             .get_result(&mut conn)?;
 
+        quran_word.word = with_waqf(
+            quran_word.word,
+            WaqfMark::from_option_string(quran_word.waqf.clone()),
+        );
+
         Ok(web::Json(quran_word))
     })
     .await
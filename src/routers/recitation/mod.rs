@@ -0,0 +1,255 @@
+pub mod recitation_add;
+pub mod recitation_ayah_delete;
+pub mod recitation_ayah_modify;
+pub mod recitation_ayah_view;
+pub mod recitation_delete;
+pub mod recitation_edit;
+pub mod recitation_list;
+pub mod recitation_view;
+pub mod reciter_add;
+pub mod reciter_delete;
+pub mod reciter_edit;
+pub mod reciter_list;
+pub mod reciter_view;
+
+use std::collections::HashMap;
+
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Request body of /reciter, the reciter can be
+/// an account or just a name
+#[derive(Deserialize)]
+pub struct SimpleReciter {
+    pub name: String,
+    pub account_uuid: Option<Uuid>,
+}
+
+#[derive(Serialize)]
+pub struct ReciterItem {
+    pub uuid: Uuid,
+    pub name: String,
+    pub account_uuid: Option<Uuid>,
+}
+
+#[derive(Deserialize)]
+pub struct SimpleRecitation {
+    pub reciter_uuid: Uuid,
+    pub mushaf_uuid: Uuid,
+
+    /// Like murattal or mujawwad
+    pub style: Option<String>,
+    pub source: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct EditableSimpleRecitation {
+    pub reciter_uuid: Uuid,
+    pub style: Option<String>,
+    pub source: Option<String>,
+}
+
+/// The query for /recitation
+/// example /recitation?mushaf=hafs&reciter={uuid}
+#[derive(Deserialize)]
+pub struct RecitationListQuery {
+    mushaf: String,
+    reciter: Option<Uuid>,
+}
+
+#[derive(Serialize)]
+pub struct RecitationItem {
+    pub uuid: Uuid,
+    pub mushaf_uuid: Uuid,
+    pub style: Option<String>,
+    pub source: Option<String>,
+    pub reciter: ReciterItem,
+}
+
+/// The response type for /recitation/{uuid}
+#[derive(Serialize)]
+pub struct RecitationView {
+    #[serde(flatten)]
+    pub recitation: RecitationItem,
+
+    /// Count of the ayahs that have audio
+    pub number_of_ayahs: i64,
+}
+
+/// The query for /recitation/{uuid}/ayah
+/// example /recitation/{uuid}/ayah?ayah_uuid={uuid}
+#[derive(Deserialize)]
+pub struct RecitationAyahQuery {
+    ayah_uuid: Uuid,
+}
+
+#[derive(Deserialize)]
+pub struct SimpleWordTiming {
+    start: i32,
+    end: i32,
+}
+
+/// Request body of /recitation/{uuid}/ayah
+///
+/// Times are in milliseconds, words are optional but when given
+/// every word of the ayah must have its time, in order
+#[derive(Deserialize)]
+pub struct SimpleRecitationAyah {
+    url: String,
+    duration: i32,
+
+    #[serde(default)]
+    words: Vec<SimpleWordTiming>,
+}
+
+/// Time of a word in the ayah audio, word is the
+/// position of the word in the ayah (starting from 1)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WordTiming {
+    pub word: u32,
+    pub start: i32,
+    pub end: i32,
+}
+
+/// Audio of a single ayah in a recitation
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct AyahAudio {
+    pub url: String,
+
+    /// In milliseconds
+    pub duration: i32,
+    pub words: Vec<WordTiming>,
+}
+
+/// Checks the duration and word timings of an ayah audio,
+/// returns the problem if any
+fn check_ayah_audio(audio: &SimpleRecitationAyah, ayah_words: usize) -> Result<(), String> {
+    if audio.duration <= 0 {
+        return Err("duration must be positive".to_string());
+    }
+
+    if audio.words.is_empty() {
+        return Ok(());
+    }
+
+    if audio.words.len() != ayah_words {
+        return Err(format!(
+            "ayah has {} words but {} word timings are given",
+            ayah_words,
+            audio.words.len()
+        ));
+    }
+
+    for (index, timing) in audio.words.iter().enumerate() {
+        if timing.start < 0 || timing.start > timing.end || timing.end > audio.duration {
+            return Err(format!(
+                "time of word {} is not in the audio duration",
+                index + 1
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the audio of the ayahs in the recitation, by ayah uuid
+pub fn ayah_audios(
+    conn: &mut PgConnection,
+    recitation: Uuid,
+    ayahs: &[i32],
+) -> QueryResult<HashMap<Uuid, AyahAudio>> {
+    use crate::schema::quran_ayahs::dsl::{quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_recitation_ayahs::dsl::{
+        ayah_id as audio_ayah_id, duration, id as audio_id, quran_recitation_ayahs,
+        recitation_id as audio_recitation_id, url,
+    };
+    use crate::schema::quran_recitation_words::dsl::{
+        end_time, quran_recitation_words, recitation_ayah_id, start_time, word_id as timing_word_id,
+    };
+    use crate::schema::quran_recitations::dsl::{
+        id as recitation_id, quran_recitations, uuid as recitation_uuid,
+    };
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, id as word_id, quran_words, word_number,
+    };
+
+    let recitation: i32 = quran_recitations
+        .filter(recitation_uuid.eq(recitation))
+        .select(recitation_id)
+        .get_result(conn)?;
+
+    let audios: Vec<(i32, Uuid, String, i32)> = quran_recitation_ayahs
+        .inner_join(quran_ayahs)
+        .filter(audio_recitation_id.eq(recitation))
+        .filter(audio_ayah_id.eq_any(ayahs))
+        .select((audio_id, ayah_uuid, url, duration))
+        .get_results(conn)?;
+
+    // word id -> position of the word in its ayah
+    let positions: HashMap<i32, i32> = quran_words
+        .filter(word_ayah_id.eq_any(ayahs))
+        .select((word_id, word_number))
+        .get_results::<(i32, i32)>(conn)?
+        .into_iter()
+        .collect();
+
+    let mut timings: HashMap<i32, Vec<WordTiming>> = HashMap::new();
+
+    for (audio, w_id, start, end) in quran_recitation_words
+        .filter(recitation_ayah_id.eq_any(audios.iter().map(|audio| audio.0)))
+        .order(start_time.asc())
+        .select((recitation_ayah_id, timing_word_id, start_time, end_time))
+        .get_results::<(i32, i32, i32, i32)>(conn)?
+    {
+        timings.entry(audio).or_default().push(WordTiming {
+            word: positions.get(&w_id).copied().unwrap_or_default() as u32,
+            start,
+            end,
+        });
+    }
+
+    Ok(audios
+        .into_iter()
+        .map(|(id, a_uuid, a_url, a_duration)| {
+            (
+                a_uuid,
+                AyahAudio {
+                    url: a_url,
+                    duration: a_duration,
+                    words: timings.remove(&id).unwrap_or_default(),
+                },
+            )
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn audio(duration: i32, words: &[(i32, i32)]) -> SimpleRecitationAyah {
+        SimpleRecitationAyah {
+            url: "https://example.com/001001.mp3".to_string(),
+            duration,
+            words: words
+                .iter()
+                .map(|(start, end)| SimpleWordTiming {
+                    start: *start,
+                    end: *end,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_check_ayah_audio() {
+        assert!(check_ayah_audio(&audio(5000, &[]), 4).is_ok());
+        assert!(check_ayah_audio(&audio(5000, &[(0, 1000), (1000, 2500)]), 2).is_ok());
+
+        assert!(check_ayah_audio(&audio(0, &[]), 4).is_err());
+        assert!(check_ayah_audio(&audio(5000, &[(0, 1000)]), 2).is_err());
+        assert!(check_ayah_audio(&audio(5000, &[(1000, 500)]), 1).is_err());
+        assert!(check_ayah_audio(&audio(5000, &[(0, 6000)]), 1).is_err());
+    }
+}
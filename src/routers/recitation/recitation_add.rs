@@ -0,0 +1,57 @@
+use crate::error::RouterError;
+use crate::models::NewQuranRecitation;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::SimpleRecitation;
+
+/// Add's a new recitation of a mushaf
+pub async fn recitation_add(
+    new_recitation: web::Json<SimpleRecitation>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_recitations::dsl::quran_recitations;
+    use crate::schema::quran_reciters::dsl::{
+        id as reciter_id, quran_reciters, uuid as reciter_uuid,
+    };
+
+    let new_recitation = new_recitation.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let reciter: i32 = quran_reciters
+            .filter(reciter_uuid.eq(new_recitation.reciter_uuid))
+            .select(reciter_id)
+            .get_result(&mut conn)?;
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(new_recitation.mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        NewQuranRecitation {
+            creator_user_id: user,
+            reciter_id: reciter,
+            mushaf_id: mushaf,
+            style: new_recitation.style.as_deref(),
+            source: new_recitation.source.as_deref(),
+        }
+        .insert_into(quran_recitations)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,50 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::RecitationAyahQuery;
+
+/// Delete's the audio of a single ayah with its word timings
+pub async fn recitation_ayah_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    query: web::Query<RecitationAyahQuery>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_recitation_ayahs::dsl::{
+        ayah_id as audio_ayah_id, quran_recitation_ayahs, recitation_id as audio_recitation_id,
+    };
+    use crate::schema::quran_recitations::dsl::{
+        id as recitation_id, quran_recitations, uuid as recitation_uuid,
+    };
+
+    let target_recitation_uuid = path.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let recitation: i32 = quran_recitations
+            .filter(recitation_uuid.eq(target_recitation_uuid))
+            .select(recitation_id)
+            .get_result(&mut conn)?;
+
+        let ayah: i32 = quran_ayahs
+            .filter(ayah_uuid.eq(query.ayah_uuid))
+            .select(ayah_id)
+            .get_result(&mut conn)?;
+
+        diesel::delete(
+            quran_recitation_ayahs
+                .filter(audio_recitation_id.eq(recitation))
+                .filter(audio_ayah_id.eq(ayah)),
+        )
+        .execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,137 @@
+use crate::error::RouterError;
+use crate::models::{NewQuranRecitationAyah, NewQuranRecitationWord};
+use crate::DbPool;
+use actix_web::web;
+use diesel::{dsl::exists, prelude::*, select};
+use uuid::Uuid;
+
+use super::{check_ayah_audio, RecitationAyahQuery, SimpleRecitationAyah};
+
+/// Modify the audio of an ayah in the recitation,
+///
+/// If the ayah has audio update it (and replace the word timings),
+/// otherwise add.
+pub async fn recitation_ayah_modify(
+    path: web::Path<Uuid>,
+    new_audio: web::Json<SimpleRecitationAyah>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+    query: web::Query<RecitationAyahQuery>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_recitation_ayahs::dsl::{
+        ayah_id as audio_ayah_id, duration, id as audio_id, quran_recitation_ayahs,
+        recitation_id as audio_recitation_id, url,
+    };
+    use crate::schema::quran_recitation_words::dsl::{quran_recitation_words, recitation_ayah_id};
+    use crate::schema::quran_recitations::dsl::{
+        id as recitation_id, mushaf_id as recitation_mushaf_id, quran_recitations,
+        uuid as recitation_uuid,
+    };
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, quran_surahs};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, id as word_id, quran_words, word_number,
+    };
+
+    let target_recitation_uuid = path.into_inner();
+    let new_audio = new_audio.into_inner();
+    let data = data.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let (recitation, mushaf): (i32, i32) = quran_recitations
+            .filter(recitation_uuid.eq(target_recitation_uuid))
+            .select((recitation_id, recitation_mushaf_id))
+            .get_result(&mut conn)?;
+
+        let (ayah, ayah_mushaf): (i32, i32) = quran_ayahs
+            .inner_join(quran_surahs)
+            .filter(ayah_uuid.eq(query.ayah_uuid))
+            .select((ayah_id, surah_mushaf_id))
+            .get_result(&mut conn)?;
+
+        if ayah_mushaf != mushaf {
+            return Err(RouterError::from_predefined_with_detail(
+                "RECITATION_AUDIO_NOT_VALID",
+                "ayah is not in the recitation mushaf",
+            ));
+        }
+
+        let ayah_words: Vec<i32> = quran_words
+            .filter(word_ayah_id.eq(ayah))
+            .order(word_number.asc())
+            .select(word_id)
+            .get_results(&mut conn)?;
+
+        if let Err(detail) = check_ayah_audio(&new_audio, ayah_words.len()) {
+            return Err(RouterError::from_predefined_with_detail(
+                "RECITATION_AUDIO_NOT_VALID",
+                &detail,
+            ));
+        }
+
+        let audio_exists: bool = select(exists(
+            quran_recitation_ayahs
+                .filter(audio_recitation_id.eq(recitation))
+                .filter(audio_ayah_id.eq(ayah)),
+        ))
+        .get_result(&mut conn)?;
+
+        conn.transaction(|conn| {
+            let audio: i32 = if audio_exists {
+                let audio: i32 = diesel::update(quran_recitation_ayahs)
+                    .filter(audio_recitation_id.eq(recitation))
+                    .filter(audio_ayah_id.eq(ayah))
+                    .set((url.eq(&new_audio.url), duration.eq(new_audio.duration)))
+                    .returning(audio_id)
+                    .get_result(conn)?;
+
+                diesel::delete(quran_recitation_words.filter(recitation_ayah_id.eq(audio)))
+                    .execute(conn)?;
+
+                audio
+            } else {
+                NewQuranRecitationAyah {
+                    creator_user_id: user,
+                    recitation_id: recitation,
+                    ayah_id: ayah,
+                    url: &new_audio.url,
+                    duration: new_audio.duration,
+                }
+                .insert_into(quran_recitation_ayahs)
+                .returning(audio_id)
+                .get_result(conn)?
+            };
+
+            let timings: Vec<NewQuranRecitationWord> = ayah_words
+                .iter()
+                .zip(new_audio.words.iter())
+                .map(|(word, timing)| NewQuranRecitationWord {
+                    creator_user_id: user,
+                    recitation_ayah_id: audio,
+                    word_id: *word,
+                    start_time: timing.start,
+                    end_time: timing.end,
+                })
+                .collect();
+
+            if !timings.is_empty() {
+                timings.insert_into(quran_recitation_words).execute(conn)?;
+            }
+
+            Ok::<(), diesel::result::Error>(())
+        })?;
+
+        Ok(if audio_exists { "Updated" } else { "Added" })
+    })
+    .await
+    .unwrap()
+}
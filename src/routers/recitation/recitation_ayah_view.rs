@@ -0,0 +1,36 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{ayah_audios, AyahAudio, RecitationAyahQuery};
+
+/// Return's the audio of a single ayah in the recitation
+pub async fn recitation_ayah_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    query: web::Query<RecitationAyahQuery>,
+) -> Result<web::Json<AyahAudio>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs, uuid as ayah_uuid};
+
+    let recitation = path.into_inner();
+    let query = query.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let ayah: i32 = quran_ayahs
+            .filter(ayah_uuid.eq(query.ayah_uuid))
+            .select(ayah_id)
+            .get_result(&mut conn)?;
+
+        let audio = ayah_audios(&mut conn, recitation, &[ayah])?
+            .remove(&query.ayah_uuid)
+            .ok_or(diesel::result::Error::NotFound)?;
+
+        Ok(web::Json(audio))
+    })
+    .await
+    .unwrap()
+}
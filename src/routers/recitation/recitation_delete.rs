@@ -0,0 +1,24 @@
+use crate::{error::RouterError, DbPool};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Delete's the recitation with its audios
+pub async fn recitation_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_recitations::dsl::{quran_recitations, uuid as recitation_uuid};
+
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        diesel::delete(quran_recitations.filter(recitation_uuid.eq(path))).execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
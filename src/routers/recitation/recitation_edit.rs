@@ -0,0 +1,46 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::EditableSimpleRecitation;
+
+/// Update's single recitation
+pub async fn recitation_edit(
+    path: web::Path<Uuid>,
+    new_recitation: web::Json<EditableSimpleRecitation>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_recitations::dsl::{
+        quran_recitations, reciter_id as recitation_reciter_id, source as recitation_source,
+        style as recitation_style, uuid as recitation_uuid,
+    };
+    use crate::schema::quran_reciters::dsl::{
+        id as reciter_id, quran_reciters, uuid as reciter_uuid,
+    };
+
+    let target_recitation_uuid = path.into_inner();
+    let new_recitation = new_recitation.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let reciter: i32 = quran_reciters
+            .filter(reciter_uuid.eq(new_recitation.reciter_uuid))
+            .select(reciter_id)
+            .get_result(&mut conn)?;
+
+        diesel::update(quran_recitations.filter(recitation_uuid.eq(target_recitation_uuid)))
+            .set((
+                recitation_reciter_id.eq(reciter),
+                recitation_style.eq(new_recitation.style),
+                recitation_source.eq(new_recitation.source),
+            ))
+            .execute(&mut conn)?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,64 @@
+use crate::error::RouterError;
+use crate::models::{QuranRecitation, QuranReciter};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{RecitationItem, RecitationListQuery, ReciterItem};
+
+/// Returns the list of recitations of a mushaf
+pub async fn recitation_list(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<RecitationListQuery>,
+) -> Result<web::Json<Vec<RecitationItem>>, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, uuid as account_uuid};
+    use crate::schema::quran_mushafs::dsl::{
+        quran_mushafs, short_name as mushaf_short_name, uuid as mushaf_uuid,
+    };
+    use crate::schema::quran_recitations::dsl::{created_at, quran_recitations};
+    use crate::schema::quran_reciters::dsl::{
+        name as reciter_name, quran_reciters, uuid as reciter_uuid,
+    };
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut recitations_query = quran_recitations
+            .inner_join(quran_mushafs)
+            .inner_join(quran_reciters.left_join(app_accounts))
+            .filter(mushaf_short_name.eq(query.mushaf))
+            .order((reciter_name.asc(), created_at.asc()))
+            .select((
+                QuranRecitation::as_select(),
+                mushaf_uuid,
+                QuranReciter::as_select(),
+                account_uuid.nullable(),
+            ))
+            .into_boxed();
+
+        if let Some(requested_reciter) = query.reciter {
+            recitations_query = recitations_query.filter(reciter_uuid.eq(requested_reciter));
+        }
+
+        let recitations = recitations_query
+            .get_results::<(QuranRecitation, Uuid, QuranReciter, Option<Uuid>)>(&mut conn)?
+            .into_iter()
+            .map(|(recitation, m_uuid, reciter, account)| RecitationItem {
+                uuid: recitation.uuid,
+                mushaf_uuid: m_uuid,
+                style: recitation.style,
+                source: recitation.source,
+                reciter: ReciterItem {
+                    uuid: reciter.uuid,
+                    name: reciter.name,
+                    account_uuid: account,
+                },
+            })
+            .collect();
+
+        Ok(web::Json(recitations))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,60 @@
+use crate::error::RouterError;
+use crate::models::{QuranRecitation, QuranReciter};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{RecitationItem, RecitationView, ReciterItem};
+
+/// Return's a single recitation
+pub async fn recitation_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<RecitationView>, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, uuid as account_uuid};
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_recitation_ayahs::dsl::{quran_recitation_ayahs, recitation_id};
+    use crate::schema::quran_recitations::dsl::{quran_recitations, uuid as recitation_uuid};
+    use crate::schema::quran_reciters::dsl::quran_reciters;
+
+    let requested_recitation_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (recitation, m_uuid, reciter, account) = quran_recitations
+            .inner_join(quran_mushafs)
+            .inner_join(quran_reciters.left_join(app_accounts))
+            .filter(recitation_uuid.eq(requested_recitation_uuid))
+            .select((
+                QuranRecitation::as_select(),
+                mushaf_uuid,
+                QuranReciter::as_select(),
+                account_uuid.nullable(),
+            ))
+            .get_result::<(QuranRecitation, Uuid, QuranReciter, Option<Uuid>)>(&mut conn)?;
+
+        let number_of_ayahs: i64 = quran_recitation_ayahs
+            .filter(recitation_id.eq(recitation.id))
+            .count()
+            .get_result(&mut conn)?;
+
+        Ok(web::Json(RecitationView {
+            recitation: RecitationItem {
+                uuid: recitation.uuid,
+                mushaf_uuid: m_uuid,
+                style: recitation.style,
+                source: recitation.source,
+                reciter: ReciterItem {
+                    uuid: reciter.uuid,
+                    name: reciter.name,
+                    account_uuid: account,
+                },
+            },
+            number_of_ayahs,
+        }))
+    })
+    .await
+    .unwrap()
+}
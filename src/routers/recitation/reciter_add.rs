@@ -0,0 +1,53 @@
+use crate::error::RouterError;
+use crate::models::NewQuranReciter;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::SimpleReciter;
+
+/// Add's a new reciter
+pub async fn reciter_add(
+    new_reciter: web::Json<SimpleReciter>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, id as account_id, uuid as account_uuid};
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_reciters::dsl::quran_reciters;
+
+    let new_reciter = new_reciter.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let account: Option<i32> = match new_reciter.account_uuid {
+            Some(uuid) => Some(
+                app_accounts
+                    .filter(account_uuid.eq(uuid))
+                    .select(account_id)
+                    .get_result(&mut conn)?,
+            ),
+
+            None => None,
+        };
+
+        NewQuranReciter {
+            creator_user_id: user,
+            account_id: account,
+            name: &new_reciter.name,
+        }
+        .insert_into(quran_reciters)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
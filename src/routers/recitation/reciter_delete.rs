@@ -0,0 +1,24 @@
+use crate::{error::RouterError, DbPool};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Delete's the reciter and all of its recitations
+pub async fn reciter_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_reciters::dsl::{quran_reciters, uuid as reciter_uuid};
+
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        diesel::delete(quran_reciters.filter(reciter_uuid.eq(path))).execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,49 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::SimpleReciter;
+
+/// Update's single reciter
+pub async fn reciter_edit(
+    path: web::Path<Uuid>,
+    new_reciter: web::Json<SimpleReciter>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, id as account_id, uuid as account_uuid};
+    use crate::schema::quran_reciters::dsl::{
+        account_id as reciter_account_id, name as reciter_name, quran_reciters,
+        uuid as reciter_uuid,
+    };
+
+    let target_reciter_uuid = path.into_inner();
+    let new_reciter = new_reciter.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let account: Option<i32> = match new_reciter.account_uuid {
+            Some(uuid) => Some(
+                app_accounts
+                    .filter(account_uuid.eq(uuid))
+                    .select(account_id)
+                    .get_result(&mut conn)?,
+            ),
+
+            None => None,
+        };
+
+        diesel::update(quran_reciters.filter(reciter_uuid.eq(target_reciter_uuid)))
+            .set((
+                reciter_name.eq(new_reciter.name),
+                reciter_account_id.eq(account),
+            ))
+            .execute(&mut conn)?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
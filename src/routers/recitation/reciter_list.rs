@@ -0,0 +1,37 @@
+use crate::error::RouterError;
+use crate::models::QuranReciter;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::ReciterItem;
+
+/// Returns the list of reciters
+pub async fn reciter_list(
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<Vec<ReciterItem>>, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, uuid as account_uuid};
+    use crate::schema::quran_reciters::dsl::{name as reciter_name, quran_reciters};
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let reciters = quran_reciters
+            .left_join(app_accounts)
+            .order(reciter_name.asc())
+            .select((QuranReciter::as_select(), account_uuid.nullable()))
+            .get_results::<(QuranReciter, Option<Uuid>)>(&mut conn)?
+            .into_iter()
+            .map(|(reciter, account)| ReciterItem {
+                uuid: reciter.uuid,
+                name: reciter.name,
+                account_uuid: account,
+            })
+            .collect();
+
+        Ok(web::Json(reciters))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,37 @@
+use crate::error::RouterError;
+use crate::models::QuranReciter;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::ReciterItem;
+
+/// Return's a single reciter
+pub async fn reciter_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<web::Json<ReciterItem>, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, uuid as account_uuid};
+    use crate::schema::quran_reciters::dsl::{quran_reciters, uuid as reciter_uuid};
+
+    let requested_reciter_uuid = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (reciter, account) = quran_reciters
+            .left_join(app_accounts)
+            .filter(reciter_uuid.eq(requested_reciter_uuid))
+            .select((QuranReciter::as_select(), account_uuid.nullable()))
+            .get_result::<(QuranReciter, Option<Uuid>)>(&mut conn)?;
+
+        Ok(web::Json(ReciterItem {
+            uuid: reciter.uuid,
+            name: reciter.name,
+            account_uuid: account,
+        }))
+    })
+    .await
+    .unwrap()
+}
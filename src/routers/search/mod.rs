@@ -0,0 +1,191 @@
+pub mod quran_search;
+pub mod translation_search;
+pub mod word_concordance;
+
+use diesel::pg::Pg;
+use diesel::sql_types::Text;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Defined in the quran_words_normalized migration, it's the same function
+// that generates the quran_words.normalized_word column
+diesel::define_sql_function! {
+    fn quran_normalize_arabic(input: Text) -> Text;
+}
+
+/// PostgreSQL text search types that diesel doesn't have
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsquery", schema = "pg_catalog"))]
+    pub struct Tsquery;
+
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "regconfig", schema = "pg_catalog"))]
+    pub struct Regconfig;
+}
+
+use crate::schema::sql_types::Tsvector;
+use sql_types::{Regconfig, Tsquery};
+
+// Defined in the quran_translations_text_search migration, maps
+// a translation language to its text search configuration
+diesel::define_sql_function! {
+    fn translation_search_config(language: Text) -> Regconfig;
+}
+
+diesel::define_sql_function! {
+    fn websearch_to_tsquery(config: Regconfig, query: Text) -> Tsquery;
+}
+
+diesel::define_sql_function! {
+    fn ts_headline(config: Regconfig, document: Text, query: Tsquery, options: Text) -> Text;
+}
+
+diesel::define_sql_function! {
+    fn ts_rank(vector: Tsvector, query: Tsquery) -> Float4;
+}
+
+diesel::infix_operator!(TsMatch, " @@ ", backend: Pg);
+
+/// Options of the ts_headline, matched words are wrapped in <b></b>
+pub const SNIPPET_OPTIONS: &str = "StartSel=<b>, StopSel=</b>, MaxWords=35, MinWords=15";
+
+/// Default count of ayahs in a search result, when `to` is not given
+pub const SEARCH_DEFAULT_LIMIT: u64 = 50;
+
+#[derive(Deserialize, Clone)]
+pub struct QuranSearchQuery {
+    /// Words to search, every word must be found in the ayah
+    q: String,
+
+    /// The words are in Buckwalter transliteration
+    #[serde(default)]
+    buckwalter: bool,
+
+    /// Mushaf short name
+    mushaf: String,
+
+    from: Option<u64>,
+    to: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct QuranSearchResult {
+    pub surah_uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_uuid: Uuid,
+    pub ayah_number: i32,
+    pub text: String,
+
+    /// Positions of the matched words in the ayah, starting from 1
+    pub matched_words: Vec<u32>,
+}
+
+/// The query for /search/concordance
+/// example /search/concordance?mushaf=hafs&root=كتب
+#[derive(Deserialize, Clone)]
+pub struct ConcordanceQuery {
+    /// Mushaf short name
+    mushaf: String,
+
+    /// At least one of the root or lemma must be given
+    root: Option<String>,
+    lemma: Option<String>,
+
+    /// The root and lemma are in Buckwalter transliteration
+    #[serde(default)]
+    buckwalter: bool,
+}
+
+/// A word that has the root (or lemma)
+#[derive(Serialize, Deserialize)]
+pub struct ConcordanceWord {
+    pub uuid: Uuid,
+
+    /// Position of the word in the ayah, starting from 1
+    pub position: u32,
+    pub word: String,
+    pub lemma: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConcordanceAyah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub text: String,
+    pub words: Vec<ConcordanceWord>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ConcordanceSurah {
+    pub uuid: Uuid,
+    pub number: i32,
+    pub name: String,
+
+    /// Count of the words in the surah
+    pub count: u32,
+    pub ayahs: Vec<ConcordanceAyah>,
+}
+
+/// The response type for /search/concordance
+#[derive(Serialize, Deserialize)]
+pub struct Concordance {
+    pub root: Option<String>,
+    pub lemma: Option<String>,
+
+    /// Count of the words in the mushaf
+    pub count: u32,
+    pub ayahs_count: u32,
+    pub surahs: Vec<ConcordanceSurah>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct TranslationSearchQuery {
+    /// Web search syntax, "quoted phrases", or and -excluded words can be used
+    q: String,
+
+    /// Search only in this translation
+    translation: Option<Uuid>,
+
+    /// Search in all translations of this language,
+    /// when translation is given this will be ignored
+    language: Option<String>,
+
+    from: Option<u64>,
+    to: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TranslationSearchResult {
+    pub surah_uuid: Uuid,
+    pub surah_number: i32,
+    pub ayah_uuid: Uuid,
+    pub ayah_number: i32,
+    pub translation_uuid: Uuid,
+
+    /// Part of the translation text with the matched words highlighted
+    pub snippet: String,
+}
+
+/// Splits the search query to the words that will be used in LIKE patterns
+///
+/// The LIKE wildcards are removed from the words
+pub fn search_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .map(|term| term.replace(['%', '_', '\\'], ""))
+        .filter(|term| !term.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::search_terms;
+
+    #[test]
+    fn test_search_terms() {
+        assert_eq!(search_terms("  الحمد   لله "), vec!["الحمد", "لله"]);
+        assert_eq!(search_terms("رب% _ \\"), vec!["رب"]);
+        assert!(search_terms("   ").is_empty());
+    }
+}
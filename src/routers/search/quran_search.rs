@@ -0,0 +1,125 @@
+use std::collections::BTreeMap;
+
+use super::{
+    quran_normalize_arabic, search_terms, QuranSearchQuery, QuranSearchResult, SEARCH_DEFAULT_LIMIT,
+};
+use crate::buckwalter;
+use crate::error::RouterError;
+use crate::routers::quran::word::{with_waqf, WaqfMark};
+use crate::DbPool;
+use actix_web::web;
+use diesel::dsl::sql;
+use diesel::pg::Pg;
+use diesel::prelude::*;
+use diesel::sql_types::Bool;
+use uuid::Uuid;
+
+/// Searches the words of a mushaf
+///
+/// Harakat, tatweel and the alef/hamza/ya variants are ignored in both
+/// the query and the quran words, every word of the query must be found
+/// in the ayah
+pub async fn quran_search(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<QuranSearchQuery>,
+) -> Result<web::Json<Vec<QuranSearchResult>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+    use crate::schema::quran_surahs::dsl::{
+        number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, normalized_word, quran_words, waqf, word as q_word, word_number,
+    };
+
+    let terms = if query.buckwalter {
+        search_terms(&buckwalter::to_arabic(&query.q))
+    } else {
+        search_terms(&query.q)
+    };
+
+    if terms.is_empty() {
+        return Err(RouterError::from_predefined("SEARCH_QUERY_EMPTY"));
+    }
+
+    let patterns: Vec<String> = terms.iter().map(|term| format!("%{}%", term)).collect();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut ayahs_query = quran_ayahs
+            .inner_join(quran_surahs.inner_join(quran_mushafs))
+            .filter(mushaf_short_name.eq(&query.mushaf))
+            .select((ayah_id, ayah_uuid, ayah_number, surah_uuid, surah_number))
+            .order((surah_number.asc(), ayah_number.asc()))
+            .limit(query.to.unwrap_or(SEARCH_DEFAULT_LIMIT) as i64)
+            .offset(query.from.unwrap_or_default() as i64)
+            .into_boxed();
+
+        // Every term must match at least one word of the ayah
+        for pattern in &patterns {
+            ayahs_query = ayahs_query.filter(
+                ayah_id.eq_any(
+                    quran_words
+                        .filter(normalized_word.like(quran_normalize_arabic(pattern.clone())))
+                        .select(word_ayah_id),
+                ),
+            );
+        }
+
+        let ayahs: Vec<(i32, Uuid, i32, Uuid, i32)> = ayahs_query.get_results(&mut conn)?;
+
+        let mut matched: Box<dyn BoxableExpression<quran_words, Pg, SqlType = Bool>> =
+            Box::new(sql::<Bool>("FALSE"));
+
+        for pattern in &patterns {
+            matched =
+                Box::new(matched.or(normalized_word.like(quran_normalize_arabic(pattern.clone()))));
+        }
+
+        let words: Vec<(i32, String, Option<String>, bool)> = quran_words
+            .filter(word_ayah_id.eq_any(ayahs.iter().map(|ayah| ayah.0)))
+            .order((word_ayah_id.asc(), word_number.asc()))
+            .select((word_ayah_id, q_word, waqf, matched))
+            .get_results(&mut conn)?;
+
+        let mut words_map: BTreeMap<i32, Vec<(String, bool)>> = BTreeMap::new();
+        for (id, word, word_waqf, is_matched) in words {
+            words_map.entry(id).or_default().push((
+                with_waqf(word, WaqfMark::from_option_string(word_waqf)),
+                is_matched,
+            ));
+        }
+
+        let result = ayahs
+            .into_iter()
+            .map(|(id, a_uuid, a_number, s_uuid, s_number)| {
+                let ayah_words = words_map.remove(&id).unwrap_or_default();
+
+                QuranSearchResult {
+                    surah_uuid: s_uuid,
+                    surah_number: s_number,
+                    ayah_uuid: a_uuid,
+                    ayah_number: a_number,
+                    matched_words: ayah_words
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, (_, is_matched))| *is_matched)
+                        .map(|(index, _)| index as u32 + 1)
+                        .collect(),
+                    text: ayah_words
+                        .into_iter()
+                        .map(|(word, _)| word)
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                }
+            })
+            .collect();
+
+        Ok(web::Json(result))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,109 @@
+use super::{
+    translation_search_config, ts_headline, ts_rank, websearch_to_tsquery, TranslationSearchQuery,
+    TranslationSearchResult, TsMatch, SEARCH_DEFAULT_LIMIT, SNIPPET_OPTIONS,
+};
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Full text search over the translation texts
+///
+/// The text search configuration comes from the translation language,
+/// so the query is stemmed the same way as the texts. Results are ordered
+/// by rank
+pub async fn translation_search(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<TranslationSearchQuery>,
+) -> Result<web::Json<Vec<TranslationSearchResult>>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, uuid as ayah_uuid};
+    use crate::schema::quran_surahs::dsl::{
+        number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
+    use crate::schema::quran_translations::dsl::{
+        language as translation_language, quran_translations, uuid as translation_uuid,
+    };
+    use crate::schema::quran_translations_text::dsl::{
+        quran_translations_text, text as translation_text, text_search,
+    };
+
+    if query.q.trim().is_empty() {
+        return Err(RouterError::from_predefined("SEARCH_QUERY_EMPTY"));
+    }
+
+    if query.translation.is_none() && query.language.is_none() {
+        return Err(RouterError::from_predefined("SEARCH_SCOPE_REQUIRED"));
+    }
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        // The language decides the search configuration,
+        // we need it before building the search query
+        let language = match query.translation {
+            Some(requested_translation) => quran_translations
+                .filter(translation_uuid.eq(requested_translation))
+                .select(translation_language)
+                .get_result::<String>(&mut conn)?,
+
+            None => query.language.clone().unwrap_or_default(),
+        };
+
+        let ts_query =
+            || websearch_to_tsquery(translation_search_config(language.clone()), query.q.clone());
+
+        let mut search_query = quran_translations_text
+            .inner_join(quran_translations)
+            .inner_join(quran_ayahs.inner_join(quran_surahs))
+            .filter(TsMatch::new(text_search, ts_query()))
+            .order((
+                ts_rank(text_search, ts_query()).desc(),
+                surah_number.asc(),
+                ayah_number.asc(),
+            ))
+            .select((
+                surah_uuid,
+                surah_number,
+                ayah_uuid,
+                ayah_number,
+                translation_uuid,
+                ts_headline(
+                    translation_search_config(language.clone()),
+                    translation_text,
+                    ts_query(),
+                    SNIPPET_OPTIONS,
+                ),
+            ))
+            .limit(query.to.unwrap_or(SEARCH_DEFAULT_LIMIT) as i64)
+            .offset(query.from.unwrap_or_default() as i64)
+            .into_boxed();
+
+        search_query = match query.translation {
+            Some(requested_translation) => {
+                search_query.filter(translation_uuid.eq(requested_translation))
+            }
+
+            None => search_query.filter(translation_language.eq(language.clone())),
+        };
+
+        let result = search_query
+            .get_results::<(Uuid, i32, Uuid, i32, Uuid, String)>(&mut conn)?
+            .into_iter()
+            .map(
+                |(s_uuid, s_number, a_uuid, a_number, t_uuid, snippet)| TranslationSearchResult {
+                    surah_uuid: s_uuid,
+                    surah_number: s_number,
+                    ayah_uuid: a_uuid,
+                    ayah_number: a_number,
+                    translation_uuid: t_uuid,
+                    snippet,
+                },
+            )
+            .collect();
+
+        Ok(web::Json(result))
+    })
+    .await
+    .unwrap()
+}
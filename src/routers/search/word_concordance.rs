@@ -0,0 +1,159 @@
+use std::collections::BTreeMap;
+
+use super::{Concordance, ConcordanceAyah, ConcordanceQuery, ConcordanceSurah, ConcordanceWord};
+use crate::buckwalter;
+use crate::error::RouterError;
+use crate::routers::multip;
+use crate::routers::quran::word::{with_waqf, WaqfMark};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Surah (number, uuid, name) and ayah (number, id, uuid) of a word
+type ConcordanceAyahKey = ((i32, Uuid, String), (i32, i32, Uuid));
+
+/// Uuid, number, text, waqf and lemma of a word
+type ConcordanceWordRow = (Uuid, i32, String, Option<String>, Option<String>);
+
+/// Returns every word of the mushaf that has the root (or lemma),
+/// grouped by surah and ayah with the ayah text
+///
+/// When both are given the word must have both of them
+pub async fn word_concordance(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<ConcordanceQuery>,
+) -> Result<web::Json<Concordance>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{
+        ayah_number, id as ayah_id, quran_ayahs, uuid as ayah_uuid,
+    };
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+    use crate::schema::quran_surahs::dsl::{
+        name as surah_name, number as surah_number, quran_surahs, uuid as surah_uuid,
+    };
+    use crate::schema::quran_word_morphologies::dsl::{lemma, quran_word_morphologies, root};
+    use crate::schema::quran_words::dsl::{
+        ayah_id as word_ayah_id, quran_words, uuid as word_uuid, waqf, word as q_word, word_number,
+    };
+
+    let convert = |value: Option<String>| {
+        value
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                if query.buckwalter {
+                    buckwalter::to_arabic(&value)
+                } else {
+                    value
+                }
+            })
+    };
+
+    let requested_root = convert(query.root.clone());
+    let requested_lemma = convert(query.lemma.clone());
+
+    if requested_root.is_none() && requested_lemma.is_none() {
+        return Err(RouterError::from_predefined("SEARCH_QUERY_EMPTY"));
+    }
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut words_query = quran_word_morphologies
+            .inner_join(
+                quran_words
+                    .inner_join(quran_ayahs.inner_join(quran_surahs.inner_join(quran_mushafs))),
+            )
+            .filter(mushaf_short_name.eq(&query.mushaf))
+            .order((surah_number.asc(), ayah_number.asc(), word_number.asc()))
+            .select((
+                (
+                    (surah_number, surah_uuid, surah_name),
+                    (ayah_number, ayah_id, ayah_uuid),
+                ),
+                (word_uuid, word_number, q_word, waqf, lemma),
+            ))
+            .into_boxed();
+
+        if let Some(ref r) = requested_root {
+            words_query = words_query.filter(root.eq(r));
+        }
+
+        if let Some(ref l) = requested_lemma {
+            words_query = words_query.filter(lemma.eq(l));
+        }
+
+        let words: Vec<(ConcordanceAyahKey, ConcordanceWordRow)> =
+            words_query.get_results(&mut conn)?;
+
+        let count = words.len() as u32;
+
+        let ayah_ids: Vec<i32> = words.iter().map(|((_, (_, id, _)), _)| *id).collect();
+
+        let mut texts: BTreeMap<i32, Vec<String>> = multip(
+            quran_words
+                .filter(word_ayah_id.eq_any(ayah_ids))
+                .order((word_ayah_id.asc(), word_number.asc()))
+                .select((word_ayah_id, q_word, waqf))
+                .get_results::<(i32, String, Option<String>)>(&mut conn)?
+                .into_iter()
+                .map(|(id, word, word_waqf)| {
+                    (id, with_waqf(word, WaqfMark::from_option_string(word_waqf)))
+                })
+                .collect(),
+            |id| id,
+        );
+
+        let ayahs = multip(words, |ayah| ayah);
+        let ayahs_count = ayahs.len() as u32;
+
+        let surahs = multip(
+            ayahs
+                .into_iter()
+                .map(|((surah, ayah), ayah_words)| (surah, (ayah, ayah_words)))
+                .collect(),
+            |surah| surah,
+        )
+        .into_iter()
+        .map(|((s_number, s_uuid, s_name), surah_ayahs)| {
+            let ayahs: Vec<ConcordanceAyah> = surah_ayahs
+                .into_iter()
+                .map(|((a_number, id, a_uuid), ayah_words)| ConcordanceAyah {
+                    uuid: a_uuid,
+                    number: a_number,
+                    text: texts.remove(&id).unwrap_or_default().join(" "),
+                    words: ayah_words
+                        .into_iter()
+                        .map(
+                            |(w_uuid, w_number, word, w_waqf, w_lemma)| ConcordanceWord {
+                                uuid: w_uuid,
+                                position: w_number as u32,
+                                word: with_waqf(word, WaqfMark::from_option_string(w_waqf)),
+                                lemma: w_lemma,
+                            },
+                        )
+                        .collect(),
+                })
+                .collect();
+
+            ConcordanceSurah {
+                uuid: s_uuid,
+                number: s_number,
+                name: s_name,
+                count: ayahs.iter().map(|ayah| ayah.words.len() as u32).sum(),
+                ayahs,
+            }
+        })
+        .collect();
+
+        Ok(web::Json(Concordance {
+            root: requested_root,
+            lemma: requested_lemma,
+            count,
+            ayahs_count,
+            surahs,
+        }))
+    })
+    .await
+    .unwrap()
+}
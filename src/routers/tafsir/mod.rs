@@ -0,0 +1,153 @@
+pub mod tafsir_add;
+pub mod tafsir_delete;
+pub mod tafsir_edit;
+pub mod tafsir_entry_add;
+pub mod tafsir_entry_delete;
+pub mod tafsir_entry_edit;
+pub mod tafsir_list;
+pub mod tafsir_view;
+
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{QuranTafsir, QuranTafsirEntry};
+use crate::routers::quran::ayah_ranges;
+use crate::routers::translation::{translator_data, TranslatorData};
+
+#[derive(Deserialize)]
+pub struct SimpleTafsir {
+    pub mushaf_uuid: Uuid,
+
+    /// When not given the creator is the author
+    pub author_account_uuid: Option<Uuid>,
+    pub name: String,
+    pub language: String,
+    pub source: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct EditableSimpleTafsir {
+    pub name: String,
+    pub language: String,
+    pub source: Option<String>,
+    pub approved: bool,
+}
+
+/// The query for /tafsir
+/// example /tafsir?mushaf=hafs&language=ar
+#[derive(Deserialize)]
+pub struct TafsirListQuery {
+    mushaf: String,
+    language: Option<String>,
+    author_account: Option<Uuid>,
+}
+
+#[derive(Serialize)]
+pub struct TafsirItem {
+    pub uuid: Uuid,
+    pub name: String,
+    pub language: String,
+    pub source: Option<String>,
+
+    /// tafsir content status
+    pub approved: bool,
+
+    pub author: TranslatorData,
+}
+
+impl TafsirItem {
+    pub fn from_tafsir(conn: &mut PgConnection, tafsir: QuranTafsir) -> QueryResult<Self> {
+        let author = translator_data(conn, tafsir.author_account_id)?;
+
+        Ok(Self::with_author(tafsir, author))
+    }
+
+    /// When the author is already fetched
+    pub fn with_author(tafsir: QuranTafsir, author: TranslatorData) -> Self {
+        Self {
+            author,
+            uuid: tafsir.uuid,
+            name: tafsir.name,
+            language: tafsir.language,
+            source: tafsir.source,
+            approved: tafsir.approved,
+        }
+    }
+}
+
+/// A tafsir entry with its ayah range, start and end are both included
+#[derive(Serialize)]
+pub struct TafsirEntryItem {
+    pub uuid: Uuid,
+    pub surah_number: u32,
+    pub start_ayah_number: u32,
+    pub end_ayah_number: u32,
+    pub text: String,
+}
+
+/// The query for /tafsir/{uuid}
+/// example /tafsir/{uuid}?surah_uuid={uuid}
+#[derive(Deserialize)]
+pub struct TafsirViewQuery {
+    surah_uuid: Option<Uuid>,
+}
+
+/// The response type for /tafsir/{uuid}
+#[derive(Serialize)]
+pub struct ViewableTafsir {
+    pub mushaf_uuid: Uuid,
+
+    #[serde(flatten)]
+    pub tafsir: TafsirItem,
+    pub entries: Vec<TafsirEntryItem>,
+}
+
+/// Request body of /tafsir/{uuid}/entry
+#[derive(Deserialize)]
+pub struct SimpleTafsirEntry {
+    pub start_ayah_uuid: Uuid,
+    pub end_ayah_uuid: Uuid,
+    pub text: String,
+}
+
+/// The query for /ayah/{uuid}/tafsir
+/// example /ayah/{uuid}/tafsir?language=en
+#[derive(Deserialize)]
+pub struct AyahTafsirQuery {
+    pub language: Option<String>,
+    pub tafsir: Option<Uuid>,
+}
+
+/// A tafsir entry that covers the requested ayah
+#[derive(Serialize)]
+pub struct AyahTafsir {
+    pub tafsir: TafsirItem,
+
+    #[serde(flatten)]
+    pub entry: TafsirEntryItem,
+}
+
+/// Converts the entries to items with their ayah range,
+/// in the same order as the entries
+pub fn entry_items(
+    conn: &mut PgConnection,
+    entries: Vec<QuranTafsirEntry>,
+) -> QueryResult<Vec<TafsirEntryItem>> {
+    let ranges: Vec<(i32, i32)> = entries
+        .iter()
+        .map(|entry| (entry.start_ayah_id, entry.end_ayah_id))
+        .collect();
+
+    Ok(entries
+        .into_iter()
+        .zip(ayah_ranges(conn, &ranges)?)
+        .map(|(entry, range)| TafsirEntryItem {
+            uuid: entry.uuid,
+            surah_number: range.surah as u32,
+            start_ayah_number: range.start as u32,
+            end_ayah_number: range.end as u32,
+            text: entry.text,
+        })
+        .collect())
+}
@@ -0,0 +1,62 @@
+use crate::error::RouterError;
+use crate::models::NewQuranTafsir;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::SimpleTafsir;
+
+/// Add's a new tafsir of a mushaf
+pub async fn tafsir_add(
+    new_tafsir: web::Json<SimpleTafsir>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, id as account_id, uuid as account_uuid};
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_tafsirs::dsl::quran_tafsirs;
+
+    let new_tafsir = new_tafsir.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        // Get the creator user-id
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        // Get the author id, the creator is the author if not given
+        let author_id: i32 = match new_tafsir.author_account_uuid {
+            Some(uuid) => app_accounts
+                .filter(account_uuid.eq(uuid))
+                .select(account_id)
+                .get_result(&mut conn)?,
+
+            None => data as i32,
+        };
+
+        let mushaf: i32 = quran_mushafs
+            .filter(mushaf_uuid.eq(new_tafsir.mushaf_uuid))
+            .select(mushaf_id)
+            .get_result(&mut conn)?;
+
+        NewQuranTafsir {
+            creator_user_id: user,
+            mushaf_id: mushaf,
+            author_account_id: author_id,
+            name: &new_tafsir.name,
+            language: &new_tafsir.language,
+            source: new_tafsir.source.as_deref(),
+        }
+        .insert_into(quran_tafsirs)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,24 @@
+use crate::{error::RouterError, DbPool};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Delete's the specific tafsir with its entries
+pub async fn tafsir_delete(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_tafsirs::dsl::{quran_tafsirs, uuid as tafsir_uuid};
+
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        diesel::delete(quran_tafsirs.filter(tafsir_uuid.eq(path))).execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,39 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::EditableSimpleTafsir;
+
+/// Update's single tafsir
+pub async fn tafsir_edit(
+    path: web::Path<Uuid>,
+    new_tafsir: web::Json<EditableSimpleTafsir>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_tafsirs::dsl::{
+        approved as tafsir_approved, language as tafsir_language, name as tafsir_name,
+        quran_tafsirs, source as tafsir_source, uuid as tafsir_uuid,
+    };
+
+    let new_tafsir = new_tafsir.into_inner();
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        diesel::update(quran_tafsirs.filter(tafsir_uuid.eq(path)))
+            .set((
+                tafsir_name.eq(new_tafsir.name),
+                tafsir_language.eq(new_tafsir.language),
+                tafsir_source.eq(new_tafsir.source),
+                tafsir_approved.eq(new_tafsir.approved),
+            ))
+            .execute(&mut conn)?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,63 @@
+use crate::error::RouterError;
+use crate::models::NewQuranTafsirEntry;
+use crate::routers::quran::ayah_range;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::SimpleTafsirEntry;
+
+/// Add's a new entry to the tafsir, over a range of ayahs
+pub async fn tafsir_entry_add(
+    path: web::Path<Uuid>,
+    new_entry: web::Json<SimpleTafsirEntry>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_tafsir_entries::dsl::quran_tafsir_entries;
+    use crate::schema::quran_tafsirs::dsl::{
+        id as tafsir_id, mushaf_id as tafsir_mushaf_id, quran_tafsirs, uuid as tafsir_uuid,
+    };
+
+    let new_entry = new_entry.into_inner();
+    let path = path.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let (tafsir, mushaf): (i32, i32) = quran_tafsirs
+            .filter(tafsir_uuid.eq(path))
+            .select((tafsir_id, tafsir_mushaf_id))
+            .get_result(&mut conn)?;
+
+        let (start, end) = ayah_range(
+            &mut conn,
+            Some(mushaf),
+            new_entry.start_ayah_uuid,
+            new_entry.end_ayah_uuid,
+            "TAFSIR_RANGE_NOT_VALID",
+        )?;
+
+        NewQuranTafsirEntry {
+            creator_user_id: user,
+            tafsir_id: tafsir,
+            start_ayah_id: start,
+            end_ayah_id: end,
+            text: &new_entry.text,
+        }
+        .insert_into(quran_tafsir_entries)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,37 @@
+use crate::{error::RouterError, DbPool};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Delete's a single entry of the tafsir
+pub async fn tafsir_entry_delete(
+    path: web::Path<(Uuid, Uuid)>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_tafsir_entries::dsl::{
+        quran_tafsir_entries, tafsir_id as entry_tafsir_id, uuid as entry_uuid,
+    };
+    use crate::schema::quran_tafsirs::dsl::{id as tafsir_id, quran_tafsirs, uuid as tafsir_uuid};
+
+    let (requested_tafsir, requested_entry) = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let tafsir: i32 = quran_tafsirs
+            .filter(tafsir_uuid.eq(requested_tafsir))
+            .select(tafsir_id)
+            .get_result(&mut conn)?;
+
+        diesel::delete(
+            quran_tafsir_entries
+                .filter(entry_uuid.eq(requested_entry))
+                .filter(entry_tafsir_id.eq(tafsir)),
+        )
+        .execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
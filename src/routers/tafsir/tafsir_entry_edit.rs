@@ -0,0 +1,61 @@
+use crate::error::RouterError;
+use crate::routers::quran::ayah_range;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::SimpleTafsirEntry;
+
+/// Update's the text and the ayah range of a tafsir entry
+pub async fn tafsir_entry_edit(
+    path: web::Path<(Uuid, Uuid)>,
+    new_entry: web::Json<SimpleTafsirEntry>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_tafsir_entries::dsl::{
+        end_ayah_id, quran_tafsir_entries, start_ayah_id, tafsir_id as entry_tafsir_id,
+        text as entry_text, uuid as entry_uuid,
+    };
+    use crate::schema::quran_tafsirs::dsl::{
+        id as tafsir_id, mushaf_id as tafsir_mushaf_id, quran_tafsirs, uuid as tafsir_uuid,
+    };
+
+    let new_entry = new_entry.into_inner();
+    let (requested_tafsir, requested_entry) = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (tafsir, mushaf): (i32, i32) = quran_tafsirs
+            .filter(tafsir_uuid.eq(requested_tafsir))
+            .select((tafsir_id, tafsir_mushaf_id))
+            .get_result(&mut conn)?;
+
+        let (start, end) = ayah_range(
+            &mut conn,
+            Some(mushaf),
+            new_entry.start_ayah_uuid,
+            new_entry.end_ayah_uuid,
+            "TAFSIR_RANGE_NOT_VALID",
+        )?;
+
+        let updated = diesel::update(quran_tafsir_entries)
+            .filter(entry_uuid.eq(requested_entry))
+            .filter(entry_tafsir_id.eq(tafsir))
+            .set((
+                start_ayah_id.eq(start),
+                end_ayah_id.eq(end),
+                entry_text.eq(new_entry.text),
+            ))
+            .execute(&mut conn)?;
+
+        if updated == 0 {
+            return Err(RouterError::from_predefined("NOT_FOUND"));
+        }
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,50 @@
+use crate::error::RouterError;
+use crate::models::QuranTafsir;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::{TafsirItem, TafsirListQuery};
+
+/// Returns the list of tafsirs of a mushaf
+pub async fn tafsir_list(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<TafsirListQuery>,
+) -> Result<web::Json<Vec<TafsirItem>>, RouterError> {
+    use crate::schema::app_accounts::dsl::{app_accounts, uuid as account_uuid};
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, short_name as mushaf_short_name};
+    use crate::schema::quran_tafsirs::dsl::{
+        created_at, language as tafsir_language, name as tafsir_name, quran_tafsirs,
+    };
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut tafsirs_query = quran_tafsirs
+            .inner_join(quran_mushafs)
+            .inner_join(app_accounts)
+            .filter(mushaf_short_name.eq(query.mushaf))
+            .order((tafsir_name.asc(), created_at.asc()))
+            .select(QuranTafsir::as_select())
+            .into_boxed();
+
+        if let Some(lang) = query.language {
+            tafsirs_query = tafsirs_query.filter(tafsir_language.eq(lang));
+        }
+
+        if let Some(author) = query.author_account {
+            tafsirs_query = tafsirs_query.filter(account_uuid.eq(author));
+        }
+
+        let tafsirs = tafsirs_query.get_results::<QuranTafsir>(&mut conn)?;
+
+        let result = tafsirs
+            .into_iter()
+            .map(|tafsir| TafsirItem::from_tafsir(&mut conn, tafsir))
+            .collect::<QueryResult<Vec<TafsirItem>>>()?;
+
+        Ok(web::Json(result))
+    })
+    .await
+    .unwrap()
+}
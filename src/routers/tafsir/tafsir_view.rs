@@ -0,0 +1,63 @@
+use crate::error::RouterError;
+use crate::models::{QuranTafsir, QuranTafsirEntry};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{entry_items, TafsirItem, TafsirViewQuery, ViewableTafsir};
+
+/// Return's a single tafsir with its entries,
+/// only the entries of the surah if requested
+pub async fn tafsir_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<TafsirViewQuery>,
+) -> Result<web::Json<ViewableTafsir>, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{id as ayah_id, quran_ayahs};
+    use crate::schema::quran_mushafs::dsl::{quran_mushafs, uuid as mushaf_uuid};
+    use crate::schema::quran_surahs::dsl::{quran_surahs, uuid as surah_uuid};
+    use crate::schema::quran_tafsir_entries::dsl::start_ayah_id;
+    use crate::schema::quran_tafsirs::dsl::{quran_tafsirs, uuid as tafsir_uuid};
+
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let (tafsir, m_uuid) = quran_tafsirs
+            .inner_join(quran_mushafs)
+            .filter(tafsir_uuid.eq(path))
+            .select((QuranTafsir::as_select(), mushaf_uuid))
+            .get_result::<(QuranTafsir, Uuid)>(&mut conn)?;
+
+        let mut entries_query = QuranTafsirEntry::belonging_to(&tafsir)
+            .select(QuranTafsirEntry::as_select())
+            .into_boxed();
+
+        // Entries never pass the surah, so the start ayah decides the surah
+        if let Some(uuid) = query.surah_uuid {
+            entries_query = entries_query.filter(
+                start_ayah_id.eq_any(
+                    quran_ayahs
+                        .inner_join(quran_surahs)
+                        .filter(surah_uuid.eq(uuid))
+                        .select(ayah_id),
+                ),
+            );
+        }
+
+        let entries = entries_query.get_results::<QuranTafsirEntry>(&mut conn)?;
+
+        let mut entries = entry_items(&mut conn, entries)?;
+        entries.sort_by_key(|entry| (entry.surah_number, entry.start_ayah_number));
+
+        Ok(web::Json(ViewableTafsir {
+            mushaf_uuid: m_uuid,
+            entries,
+            tafsir: TafsirItem::from_tafsir(&mut conn, tafsir)?,
+        }))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,206 @@
+pub mod topic_add;
+pub mod topic_ayah_add;
+pub mod topic_ayah_delete;
+pub mod topic_delete;
+pub mod topic_edit;
+pub mod topic_list;
+pub mod topic_view;
+
+use std::collections::HashMap;
+
+use diesel::dsl::exists;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::RouterError;
+use crate::models::QuranTopic;
+use crate::routers::quran::surah::{AyahTy, Format};
+
+#[derive(Deserialize)]
+pub struct SimpleTopic {
+    pub name_phrase: String,
+
+    /// When not given the topic is a root topic
+    pub parent_uuid: Option<Uuid>,
+}
+
+/// The query for /topic
+/// example /topic?parent={uuid}&lang_code=en
+#[derive(Deserialize)]
+pub struct TopicListQuery {
+    /// When not given the root topics are returned
+    parent: Option<Uuid>,
+    lang_code: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct TopicItem {
+    pub uuid: Uuid,
+    pub name_phrase: String,
+
+    /// Translation of the name phrase in the requested language
+    pub name: Option<String>,
+}
+
+/// The query for /topic/{uuid}
+/// example /topic/{uuid}?mushaf=hafs&lang_code=en
+#[derive(Deserialize)]
+pub struct TopicViewQuery {
+    /// Only the ranges of the mushaf
+    mushaf: Option<String>,
+    lang_code: Option<String>,
+
+    #[serde(default)]
+    format: Format,
+}
+
+/// A linked ayah range of the topic with its ayahs,
+/// start and end are both included
+#[derive(Serialize)]
+pub struct TopicAyahRange {
+    pub uuid: Uuid,
+    pub mushaf_uuid: Uuid,
+    pub surah_number: u32,
+    pub start_ayah_number: u32,
+    pub end_ayah_number: u32,
+    pub ayahs: Vec<AyahTy>,
+}
+
+/// The response type for /topic/{uuid}
+#[derive(Serialize)]
+pub struct ViewableTopic {
+    #[serde(flatten)]
+    pub topic: TopicItem,
+
+    /// Parents of the topic, starting from the root
+    pub path: Vec<TopicItem>,
+    pub children: Vec<TopicItem>,
+    pub ranges: Vec<TopicAyahRange>,
+}
+
+/// Request body of /topic/{uuid}/ayah
+#[derive(Deserialize)]
+pub struct SimpleTopicAyah {
+    pub start_ayah_uuid: Uuid,
+    pub end_ayah_uuid: Uuid,
+}
+
+/// Converts the topics to items, names are translated to the
+/// language (en by default)
+pub fn topic_items(
+    conn: &mut PgConnection,
+    topics: Vec<QuranTopic>,
+    lang: Option<&str>,
+) -> QueryResult<Vec<TopicItem>> {
+    use crate::schema::app_phrase_translations::dsl::{
+        app_phrase_translations, language as p_t_lang, text as p_t_text,
+    };
+    use crate::schema::app_phrases::dsl::{app_phrases, phrase as p_phrase};
+
+    let phrases: Vec<&String> = topics.iter().map(|topic| &topic.name_phrase).collect();
+
+    let mut names: HashMap<String, String> = app_phrases
+        .inner_join(app_phrase_translations)
+        .filter(p_t_lang.eq(lang.unwrap_or("en")))
+        .filter(p_phrase.eq_any(phrases))
+        .select((p_phrase, p_t_text))
+        .get_results::<(String, String)>(conn)?
+        .into_iter()
+        .collect();
+
+    Ok(topics
+        .into_iter()
+        .map(|topic| TopicItem {
+            uuid: topic.uuid,
+            name: names.remove(&topic.name_phrase),
+            name_phrase: topic.name_phrase,
+        })
+        .collect())
+}
+
+/// Returns the parents of the topic, starting from the root
+pub fn topic_path(conn: &mut PgConnection, topic: &QuranTopic) -> QueryResult<Vec<QuranTopic>> {
+    use crate::schema::quran_topics::dsl::{id as topic_id, quran_topics};
+
+    let mut path = vec![];
+    let mut parent = topic.parent_id;
+
+    while let Some(parent_id) = parent {
+        let parent_topic: QuranTopic = quran_topics
+            .filter(topic_id.eq(parent_id))
+            .select(QuranTopic::as_select())
+            .get_result(conn)?;
+
+        parent = parent_topic.parent_id;
+        path.push(parent_topic);
+    }
+
+    path.reverse();
+
+    Ok(path)
+}
+
+/// Checks that the name phrase exists
+pub fn check_name_phrase(conn: &mut PgConnection, name_phrase: &str) -> Result<(), RouterError> {
+    use crate::schema::app_phrases::dsl::{app_phrases, phrase as p_phrase};
+
+    let phrase_exists: bool =
+        diesel::select(exists(app_phrases.filter(p_phrase.eq(name_phrase)))).get_result(conn)?;
+
+    if !phrase_exists {
+        return Err(RouterError::from_predefined("PHRASE_NOT_FOUND"));
+    }
+
+    Ok(())
+}
+
+/// Checks that the topic is not the parent itself or
+/// one of the parents of the parent
+fn check_topic_parent(topic: i32, parent: i32, parent_path: &[i32]) -> Result<(), String> {
+    if topic == parent || parent_path.contains(&topic) {
+        return Err("topic can't be under itself".to_string());
+    }
+
+    Ok(())
+}
+
+/// Returns the id of the parent topic, if the topic is given
+/// the parent must not be under the topic
+pub fn parent_topic(
+    conn: &mut PgConnection,
+    topic: Option<i32>,
+    parent_uuid: Uuid,
+) -> Result<i32, RouterError> {
+    use crate::schema::quran_topics::dsl::{quran_topics, uuid as topic_uuid};
+
+    let parent: QuranTopic = quran_topics
+        .filter(topic_uuid.eq(parent_uuid))
+        .select(QuranTopic::as_select())
+        .get_result(conn)?;
+
+    if let Some(topic) = topic {
+        let parent_path: Vec<i32> = topic_path(conn, &parent)?
+            .into_iter()
+            .map(|topic| topic.id)
+            .collect();
+
+        check_topic_parent(topic, parent.id, &parent_path).map_err(|err| {
+            RouterError::from_predefined_with_detail("TOPIC_PARENT_NOT_VALID", &err)
+        })?;
+    }
+
+    Ok(parent.id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_topic_parent() {
+        assert!(check_topic_parent(3, 2, &[1]).is_ok());
+        assert!(check_topic_parent(3, 3, &[1]).is_err());
+        assert!(check_topic_parent(1, 3, &[1, 2]).is_err());
+    }
+}
@@ -0,0 +1,48 @@
+use crate::error::RouterError;
+use crate::models::NewQuranTopic;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::{check_name_phrase, parent_topic, SimpleTopic};
+
+/// Add's a new topic, under the parent if given
+pub async fn topic_add(
+    new_topic: web::Json<SimpleTopic>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_topics::dsl::quran_topics;
+
+    let new_topic = new_topic.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        check_name_phrase(&mut conn, &new_topic.name_phrase)?;
+
+        let parent = new_topic
+            .parent_uuid
+            .map(|uuid| parent_topic(&mut conn, None, uuid))
+            .transpose()?;
+
+        NewQuranTopic {
+            creator_user_id: user,
+            parent_id: parent,
+            name_phrase: &new_topic.name_phrase,
+        }
+        .insert_into(quran_topics)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
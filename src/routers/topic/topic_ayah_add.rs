@@ -0,0 +1,60 @@
+use crate::error::RouterError;
+use crate::models::NewQuranTopicAyah;
+use crate::routers::quran::ayah_range;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::SimpleTopicAyah;
+
+/// Links a range of ayahs to the topic
+pub async fn topic_ayah_add(
+    path: web::Path<Uuid>,
+    new_range: web::Json<SimpleTopicAyah>,
+    pool: web::Data<DbPool>,
+    data: web::ReqData<u32>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::app_users::dsl::{account_id as user_acc_id, app_users, id as user_id};
+    use crate::schema::quran_topic_ayahs::dsl::quran_topic_ayahs;
+    use crate::schema::quran_topics::dsl::{id as topic_id, quran_topics, uuid as topic_uuid};
+
+    let new_range = new_range.into_inner();
+    let path = path.into_inner();
+    let data = data.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let user: i32 = app_users
+            .filter(user_acc_id.eq(data as i32))
+            .select(user_id)
+            .get_result(&mut conn)?;
+
+        let topic: i32 = quran_topics
+            .filter(topic_uuid.eq(path))
+            .select(topic_id)
+            .get_result(&mut conn)?;
+
+        let (start, end) = ayah_range(
+            &mut conn,
+            None,
+            new_range.start_ayah_uuid,
+            new_range.end_ayah_uuid,
+            "TOPIC_RANGE_NOT_VALID",
+        )?;
+
+        NewQuranTopicAyah {
+            creator_user_id: user,
+            topic_id: topic,
+            start_ayah_id: start,
+            end_ayah_id: end,
+        }
+        .insert_into(quran_topic_ayahs)
+        .execute(&mut conn)?;
+
+        Ok("Added")
+    })
+    .await
+    .unwrap()
+}
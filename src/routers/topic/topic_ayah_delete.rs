@@ -0,0 +1,37 @@
+use crate::{error::RouterError, DbPool};
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+/// Removes a single ayah range of the topic
+pub async fn topic_ayah_delete(
+    path: web::Path<(Uuid, Uuid)>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_topic_ayahs::dsl::{
+        quran_topic_ayahs, topic_id as range_topic_id, uuid as range_uuid,
+    };
+    use crate::schema::quran_topics::dsl::{id as topic_id, quran_topics, uuid as topic_uuid};
+
+    let (requested_topic, requested_range) = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let topic: i32 = quran_topics
+            .filter(topic_uuid.eq(requested_topic))
+            .select(topic_id)
+            .get_result(&mut conn)?;
+
+        diesel::delete(
+            quran_topic_ayahs
+                .filter(range_uuid.eq(requested_range))
+                .filter(range_topic_id.eq(topic)),
+        )
+        .execute(&mut conn)?;
+
+        Ok("Deleted")
+    })
+    .await
+    .unwrap()
+}
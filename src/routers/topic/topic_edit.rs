@@ -0,0 +1,46 @@
+use crate::error::RouterError;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{check_name_phrase, parent_topic, SimpleTopic};
+
+/// Update's single topic, the topic can be moved under
+/// another topic but not under itself
+pub async fn topic_edit(
+    path: web::Path<Uuid>,
+    new_topic: web::Json<SimpleTopic>,
+    pool: web::Data<DbPool>,
+) -> Result<&'static str, RouterError> {
+    use crate::schema::quran_topics::dsl::{
+        id as topic_id, name_phrase, parent_id, quran_topics, uuid as topic_uuid,
+    };
+
+    let new_topic = new_topic.into_inner();
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let topic: i32 = quran_topics
+            .filter(topic_uuid.eq(path))
+            .select(topic_id)
+            .get_result(&mut conn)?;
+
+        check_name_phrase(&mut conn, &new_topic.name_phrase)?;
+
+        let parent = new_topic
+            .parent_uuid
+            .map(|uuid| parent_topic(&mut conn, Some(topic), uuid))
+            .transpose()?;
+
+        diesel::update(quran_topics.filter(topic_id.eq(topic)))
+            .set((name_phrase.eq(new_topic.name_phrase), parent_id.eq(parent)))
+            .execute(&mut conn)?;
+
+        Ok("Edited")
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,47 @@
+use crate::error::RouterError;
+use crate::models::QuranTopic;
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+
+use super::{topic_items, TopicItem, TopicListQuery};
+
+/// Returns the sub topics of the parent, or the root topics
+pub async fn topic_list(
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<TopicListQuery>,
+) -> Result<web::Json<Vec<TopicItem>>, RouterError> {
+    use crate::schema::quran_topics::dsl::{
+        id as topic_id, name_phrase, parent_id, quran_topics, uuid as topic_uuid,
+    };
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+
+        let mut topics_query = quran_topics
+            .order(name_phrase.asc())
+            .select(QuranTopic::as_select())
+            .into_boxed();
+
+        if let Some(parent) = query.parent {
+            let parent: i32 = quran_topics
+                .filter(topic_uuid.eq(parent))
+                .select(topic_id)
+                .get_result(&mut conn)?;
+
+            topics_query = topics_query.filter(parent_id.eq(parent));
+        } else {
+            topics_query = topics_query.filter(parent_id.is_null());
+        }
+
+        let topics = topics_query.get_results::<QuranTopic>(&mut conn)?;
+
+        Ok(web::Json(topic_items(
+            &mut conn,
+            topics,
+            query.lang_code.as_deref(),
+        )?))
+    })
+    .await
+    .unwrap()
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::error::RouterError;
+use crate::models::{QuranTopic, QuranTopicAyah};
+use crate::routers::quran::{ayah_ranges, ayahs_in_range, AyahRange};
+use crate::DbPool;
+use actix_web::web;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use super::{topic_items, topic_path, TopicAyahRange, TopicViewQuery, ViewableTopic};
+
+/// Return's a single topic with its sub topics and the ayahs
+/// of its ranges, only the ranges of the mushaf if requested
+pub async fn topic_view(
+    path: web::Path<Uuid>,
+    pool: web::Data<DbPool>,
+    web::Query(query): web::Query<TopicViewQuery>,
+) -> Result<web::Json<ViewableTopic>, RouterError> {
+    use crate::schema::quran_mushafs::dsl::{
+        id as mushaf_id, quran_mushafs, short_name as mushaf_short_name, uuid as mushaf_uuid,
+    };
+    use crate::schema::quran_topics::dsl::{
+        name_phrase, parent_id, quran_topics, uuid as topic_uuid,
+    };
+
+    let path = path.into_inner();
+
+    web::block(move || {
+        let mut conn = pool.get().unwrap();
+        let lang = query.lang_code.as_deref();
+
+        let topic: QuranTopic = quran_topics
+            .filter(topic_uuid.eq(path))
+            .select(QuranTopic::as_select())
+            .get_result(&mut conn)?;
+
+        let topic_path = topic_path(&mut conn, &topic)?;
+
+        let children: Vec<QuranTopic> = quran_topics
+            .filter(parent_id.eq(topic.id))
+            .order(name_phrase.asc())
+            .select(QuranTopic::as_select())
+            .get_results(&mut conn)?;
+
+        let ranges: Vec<QuranTopicAyah> = QuranTopicAyah::belonging_to(&topic)
+            .select(QuranTopicAyah::as_select())
+            .get_results(&mut conn)?;
+
+        let numbers = ayah_ranges(
+            &mut conn,
+            &ranges
+                .iter()
+                .map(|range| (range.start_ayah_id, range.end_ayah_id))
+                .collect::<Vec<(i32, i32)>>(),
+        )?;
+
+        let mushaf_ids: Vec<i32> = numbers.iter().map(|range| range.mushaf).collect();
+
+        // Uuid and short name of the mushafs of the ranges
+        let mushafs: HashMap<i32, (Uuid, Option<String>)> = quran_mushafs
+            .filter(mushaf_id.eq_any(mushaf_ids))
+            .select((mushaf_id, (mushaf_uuid, mushaf_short_name)))
+            .get_results::<(i32, (Uuid, Option<String>))>(&mut conn)?
+            .into_iter()
+            .collect();
+
+        let mut ranges: Vec<(AyahRange, QuranTopicAyah)> = numbers
+            .into_iter()
+            .zip(ranges)
+            .filter(|(numbers, _)| {
+                query.mushaf.is_none() || mushafs[&numbers.mushaf].1 == query.mushaf
+            })
+            .collect();
+
+        ranges.sort_by_key(|(numbers, _)| (numbers.mushaf, numbers.surah, numbers.start));
+
+        let ranges = ranges
+            .into_iter()
+            .map(|(numbers, range)| {
+                let AyahRange {
+                    mushaf,
+                    surah,
+                    start,
+                    end,
+                } = numbers;
+
+                // Ranges never pass the surah
+                let ayahs = ayahs_in_range(
+                    &mut conn,
+                    mushaf,
+                    (surah, start),
+                    (surah, end),
+                    query.format,
+                )?
+                .into_iter()
+                .next()
+                .map(|surah| surah.ayahs)
+                .unwrap_or_default();
+
+                Ok(TopicAyahRange {
+                    uuid: range.uuid,
+                    mushaf_uuid: mushafs[&mushaf].0,
+                    surah_number: surah as u32,
+                    start_ayah_number: start as u32,
+                    end_ayah_number: end as u32,
+                    ayahs,
+                })
+            })
+            .collect::<QueryResult<Vec<TopicAyahRange>>>()?;
+
+        Ok(web::Json(ViewableTopic {
+            topic: topic_items(&mut conn, vec![topic], lang)?.remove(0),
+            path: topic_items(&mut conn, topic_path, lang)?,
+            children: topic_items(&mut conn, children, lang)?,
+            ranges,
+        }))
+    })
+    .await
+    .unwrap()
+}
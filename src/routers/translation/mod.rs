@@ -8,12 +8,13 @@ pub mod translation_text_view;
 pub mod translation_view;
 
 use chrono::NaiveDate;
+use diesel::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::filter::{Filters, Order};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct TranslatorData {
     account_uuid: Uuid,
     username: String,
@@ -21,6 +22,36 @@ pub struct TranslatorData {
     last_name: Option<String>,
 }
 
+/// Returns the translator (or author) account with its primary name
+pub fn translator_data(conn: &mut PgConnection, account: i32) -> QueryResult<TranslatorData> {
+    use crate::schema::app_accounts::dsl::{
+        app_accounts, id as account_table_id, username as acc_username, uuid as account_uuid,
+    };
+    use crate::schema::app_user_names::dsl::{
+        app_user_names, first_name as user_first_name, last_name as user_last_name,
+        primary_name as user_primary_name,
+    };
+
+    let (uuid, username, first_name, last_name) = app_accounts
+        .left_join(app_user_names)
+        .filter(account_table_id.eq(account))
+        .filter(user_primary_name.eq(true).or(user_primary_name.is_null()))
+        .select((
+            account_uuid,
+            acc_username,
+            user_first_name.nullable(),
+            user_last_name.nullable(),
+        ))
+        .get_result::<(Uuid, String, Option<String>, Option<String>)>(conn)?;
+
+    Ok(TranslatorData {
+        account_uuid: uuid,
+        username,
+        first_name,
+        last_name,
+    })
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct SimpleTranslation {
     pub translator_account_uuid: Option<Uuid>,
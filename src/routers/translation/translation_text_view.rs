@@ -47,6 +47,7 @@ pub async fn translation_text_view(
         let translation_text: TranslationText = quran_translations_text
             .filter(text_ayah_id.eq(ayah))
             .filter(text_translation_id.eq(translation))
+            .select(TranslationText::as_select())
             .get_result(&mut conn)?;
 
         Ok(web::Json(translation_text))
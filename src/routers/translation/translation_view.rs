@@ -1,6 +1,7 @@
 use crate::error::RouterError;
 use crate::models::Translation;
-use crate::{DbPool, TranslationAyah, TranslationStatus, TranslatorData, ViewableTranslation};
+use crate::routers::translation::translator_data;
+use crate::{DbPool, TranslationAyah, TranslationStatus, ViewableTranslation};
 use ::uuid::Uuid;
 use actix_web::web;
 use diesel::{prelude::*, query_dsl::boxed_dsl::BoxedDsl};
@@ -17,13 +18,6 @@ pub async fn translation_view(
     pool: web::Data<DbPool>,
     web::Query(query): web::Query<TranslationViewQuery>,
 ) -> Result<web::Json<ViewableTranslation>, RouterError> {
-    use crate::schema::app_accounts::dsl::{
-        app_accounts, id as account_table_id, username as acc_username, uuid as account_uuid,
-    };
-    use crate::schema::app_user_names::dsl::{
-        app_user_names, first_name as user_first_name, last_name as user_last_name,
-        primary_name as user_primary_name,
-    };
     use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs, uuid as ayah_uuid};
     use crate::schema::quran_mushafs::dsl::{
         id as mushaf_table_id, quran_mushafs, uuid as mushaf_table_uuid,
@@ -53,17 +47,7 @@ pub async fn translation_view(
             .select(mushaf_table_uuid)
             .get_result(&mut conn)?;
 
-        let translator = app_accounts
-            .left_join(app_user_names)
-            .filter(account_table_id.eq(translation.translator_account_id))
-            .filter(user_primary_name.eq(true).or(user_primary_name.is_null()))
-            .select((
-                account_uuid,
-                acc_username,
-                user_first_name.nullable(),
-                user_last_name.nullable(),
-            ))
-            .get_result::<(Uuid, String, Option<String>, Option<String>)>(&mut conn)?;
+        let translator = translator_data(&mut conn, translation.translator_account_id)?;
 
         let mut ayahs = quran_surahs
             .inner_join(quran_ayahs.left_outer_join(quran_translations_text))
@@ -117,12 +101,7 @@ pub async fn translation_view(
             language: translation.language,
             release_date: translation.release_date,
             mushaf_uuid,
-            translator: TranslatorData {
-                account_uuid: translator.0,
-                username: translator.1,
-                first_name: translator.2,
-                last_name: translator.3,
-            },
+            translator,
             bismillah: translation.bismillah,
         }))
     })
@@ -1,5 +1,11 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "tsvector", schema = "pg_catalog"))]
+    pub struct Tsvector;
+}
+
 diesel::table! {
     app_accounts (id) {
         id -> Int4,
@@ -177,6 +183,18 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    quran_ayah_markers (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        ayah_id -> Int4,
+        marker -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     quran_ayahs (id) {
         id -> Int4,
@@ -190,6 +208,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    quran_divisions (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        mushaf_id -> Int4,
+        division_type -> Varchar,
+        number -> Int4,
+        start_ayah_id -> Int4,
+        end_ayah_id -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     quran_mushafs (id) {
         id -> Int4,
@@ -204,6 +237,58 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    quran_recitation_ayahs (id) {
+        id -> Int4,
+        creator_user_id -> Int4,
+        recitation_id -> Int4,
+        ayah_id -> Int4,
+        url -> Text,
+        duration -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_recitation_words (id) {
+        id -> Int4,
+        creator_user_id -> Int4,
+        recitation_ayah_id -> Int4,
+        word_id -> Int4,
+        start_time -> Int4,
+        end_time -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_recitations (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        reciter_id -> Int4,
+        mushaf_id -> Int4,
+        style -> Nullable<Varchar>,
+        source -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_reciters (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        account_id -> Nullable<Int4>,
+        name -> Varchar,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
 diesel::table! {
     quran_surahs (id) {
         id -> Int4,
@@ -220,6 +305,63 @@ diesel::table! {
         name_transliteration -> Nullable<Text>,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        revelation_order -> Nullable<Int4>,
+        rukus -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    quran_tafsir_entries (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        tafsir_id -> Int4,
+        start_ayah_id -> Int4,
+        end_ayah_id -> Int4,
+        text -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_tafsirs (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        mushaf_id -> Int4,
+        creator_user_id -> Int4,
+        author_account_id -> Int4,
+        name -> Varchar,
+        language -> Varchar,
+        source -> Nullable<Varchar>,
+        approved -> Bool,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_topic_ayahs (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        topic_id -> Int4,
+        start_ayah_id -> Int4,
+        end_ayah_id -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_topics (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        parent_id -> Nullable<Int4>,
+        name_phrase -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -241,6 +383,9 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::Tsvector;
+
     quran_translations_text (id) {
         id -> Int4,
         uuid -> Uuid,
@@ -250,6 +395,91 @@ diesel::table! {
         text -> Text,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        text_search -> Tsvector,
+    }
+}
+
+diesel::table! {
+    quran_word_layouts (id) {
+        id -> Int4,
+        creator_user_id -> Int4,
+        word_id -> Int4,
+        page -> Int4,
+        line -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_word_morphologies (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        word_id -> Int4,
+        root -> Nullable<Text>,
+        lemma -> Nullable<Text>,
+        part_of_speech -> Varchar,
+        person -> Nullable<Varchar>,
+        gender -> Nullable<Varchar>,
+        grammatical_number -> Nullable<Varchar>,
+        grammatical_case -> Nullable<Varchar>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_word_segments (id) {
+        id -> Int4,
+        creator_user_id -> Int4,
+        morphology_id -> Int4,
+        segment_number -> Int4,
+        form -> Text,
+        tag -> Varchar,
+        features -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_word_scripts (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        word_id -> Int4,
+        script -> Varchar,
+        text -> Text,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_word_tajweeds (id) {
+        id -> Int4,
+        creator_user_id -> Int4,
+        word_id -> Int4,
+        rule -> Varchar,
+        start_index -> Int4,
+        end_index -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    quran_word_translations (id) {
+        id -> Int4,
+        uuid -> Uuid,
+        creator_user_id -> Int4,
+        word_id -> Int4,
+        language -> Varchar,
+        text -> Text,
+        transliteration -> Nullable<Text>,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
     }
 }
 
@@ -262,6 +492,9 @@ diesel::table! {
         word -> Text,
         created_at -> Timestamptz,
         updated_at -> Timestamptz,
+        normalized_word -> Text,
+        word_number -> Int4,
+        waqf -> Nullable<Varchar>,
     }
 }
 
@@ -281,17 +514,52 @@ diesel::joinable!(app_tokens -> app_accounts (account_id));
 diesel::joinable!(app_user_names -> app_accounts (account_id));
 diesel::joinable!(app_user_names -> app_users (creator_user_id));
 diesel::joinable!(app_users -> app_accounts (account_id));
+diesel::joinable!(quran_ayah_markers -> app_users (creator_user_id));
+diesel::joinable!(quran_ayah_markers -> quran_ayahs (ayah_id));
 diesel::joinable!(quran_ayahs -> app_users (creator_user_id));
 diesel::joinable!(quran_ayahs -> quran_surahs (surah_id));
+diesel::joinable!(quran_divisions -> app_users (creator_user_id));
+diesel::joinable!(quran_divisions -> quran_mushafs (mushaf_id));
 diesel::joinable!(quran_mushafs -> app_users (creator_user_id));
+diesel::joinable!(quran_recitation_ayahs -> app_users (creator_user_id));
+diesel::joinable!(quran_recitation_ayahs -> quran_ayahs (ayah_id));
+diesel::joinable!(quran_recitation_ayahs -> quran_recitations (recitation_id));
+diesel::joinable!(quran_recitation_words -> app_users (creator_user_id));
+diesel::joinable!(quran_recitation_words -> quran_recitation_ayahs (recitation_ayah_id));
+diesel::joinable!(quran_recitation_words -> quran_words (word_id));
+diesel::joinable!(quran_recitations -> app_users (creator_user_id));
+diesel::joinable!(quran_recitations -> quran_mushafs (mushaf_id));
+diesel::joinable!(quran_recitations -> quran_reciters (reciter_id));
+diesel::joinable!(quran_reciters -> app_accounts (account_id));
+diesel::joinable!(quran_reciters -> app_users (creator_user_id));
 diesel::joinable!(quran_surahs -> app_users (creator_user_id));
 diesel::joinable!(quran_surahs -> quran_mushafs (mushaf_id));
+diesel::joinable!(quran_tafsir_entries -> app_users (creator_user_id));
+diesel::joinable!(quran_tafsir_entries -> quran_tafsirs (tafsir_id));
+diesel::joinable!(quran_tafsirs -> app_accounts (author_account_id));
+diesel::joinable!(quran_tafsirs -> app_users (creator_user_id));
+diesel::joinable!(quran_tafsirs -> quran_mushafs (mushaf_id));
+diesel::joinable!(quran_topic_ayahs -> app_users (creator_user_id));
+diesel::joinable!(quran_topic_ayahs -> quran_topics (topic_id));
+diesel::joinable!(quran_topics -> app_users (creator_user_id));
 diesel::joinable!(quran_translations -> app_accounts (translator_account_id));
 diesel::joinable!(quran_translations -> app_users (creator_user_id));
 diesel::joinable!(quran_translations -> quran_mushafs (mushaf_id));
 diesel::joinable!(quran_translations_text -> app_users (creator_user_id));
 diesel::joinable!(quran_translations_text -> quran_ayahs (ayah_id));
 diesel::joinable!(quran_translations_text -> quran_translations (translation_id));
+diesel::joinable!(quran_word_layouts -> app_users (creator_user_id));
+diesel::joinable!(quran_word_layouts -> quran_words (word_id));
+diesel::joinable!(quran_word_morphologies -> app_users (creator_user_id));
+diesel::joinable!(quran_word_morphologies -> quran_words (word_id));
+diesel::joinable!(quran_word_segments -> app_users (creator_user_id));
+diesel::joinable!(quran_word_segments -> quran_word_morphologies (morphology_id));
+diesel::joinable!(quran_word_scripts -> app_users (creator_user_id));
+diesel::joinable!(quran_word_scripts -> quran_words (word_id));
+diesel::joinable!(quran_word_tajweeds -> app_users (creator_user_id));
+diesel::joinable!(quran_word_tajweeds -> quran_words (word_id));
+diesel::joinable!(quran_word_translations -> app_users (creator_user_id));
+diesel::joinable!(quran_word_translations -> quran_words (word_id));
 diesel::joinable!(quran_words -> app_users (creator_user_id));
 diesel::joinable!(quran_words -> quran_ayahs (ayah_id));
 
@@ -310,10 +578,26 @@ diesel::allow_tables_to_appear_in_same_query!(
     app_user_names,
     app_users,
     app_verify_codes,
+    quran_ayah_markers,
     quran_ayahs,
+    quran_divisions,
     quran_mushafs,
+    quran_recitation_ayahs,
+    quran_recitation_words,
+    quran_recitations,
+    quran_reciters,
     quran_surahs,
+    quran_tafsir_entries,
+    quran_tafsirs,
+    quran_topic_ayahs,
+    quran_topics,
     quran_translations,
     quran_translations_text,
+    quran_word_layouts,
+    quran_word_morphologies,
+    quran_word_segments,
+    quran_word_scripts,
+    quran_word_tajweeds,
+    quran_word_translations,
     quran_words,
 );
@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io;
+
+use diesel::pg::PgConnection;
+use diesel::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::RouterError;
+use crate::import::CliOptions;
+use crate::models::NewQuranWordTajweed;
+use crate::routers::quran::word::WaqfMark;
+use crate::routers::quran::{AyahPosition, MUSHAF_INSERT_CHUNK};
+use crate::DbPool;
+
+/// Id, text and waqf sign of a word
+type TajweedWord = (i32, String, Option<String>);
+
+/// Tajweed rules of the quran-tajweed dataset
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum TajweedRule {
+    HamzatWasl,
+    LamShamsiyyah,
+    Silent,
+    Ghunnah,
+    Qalqalah,
+    Ikhfa,
+    IkhfaShafawi,
+    Iqlab,
+    IdghaamGhunnah,
+    IdghaamNoGhunnah,
+    IdghaamMutajanisayn,
+    IdghaamMutaqaribayn,
+    IdghaamShafawi,
+    #[serde(rename = "madd_2")]
+    Madd2,
+    #[serde(rename = "madd_246")]
+    Madd246,
+    #[serde(rename = "madd_6")]
+    Madd6,
+    MaddMuttasil,
+    MaddMunfasil,
+}
+
+impl TajweedRule {
+    /// Name of the rule, as it is in the dataset
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::HamzatWasl => "hamzat_wasl",
+            Self::LamShamsiyyah => "lam_shamsiyyah",
+            Self::Silent => "silent",
+            Self::Ghunnah => "ghunnah",
+            Self::Qalqalah => "qalqalah",
+            Self::Ikhfa => "ikhfa",
+            Self::IkhfaShafawi => "ikhfa_shafawi",
+            Self::Iqlab => "iqlab",
+            Self::IdghaamGhunnah => "idghaam_ghunnah",
+            Self::IdghaamNoGhunnah => "idghaam_no_ghunnah",
+            Self::IdghaamMutajanisayn => "idghaam_mutajanisayn",
+            Self::IdghaamMutaqaribayn => "idghaam_mutaqaribayn",
+            Self::IdghaamShafawi => "idghaam_shafawi",
+            Self::Madd2 => "madd_2",
+            Self::Madd246 => "madd_246",
+            Self::Madd6 => "madd_6",
+            Self::MaddMuttasil => "madd_muttasil",
+            Self::MaddMunfasil => "madd_munfasil",
+        }
+    }
+}
+
+/// A tajweed rule on a range of the ayah text
+///
+/// Start and end are character indexes of the ayah text
+/// (words and waqf signs joined by a single space), the end is not included
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TajweedAnnotation {
+    pub rule: TajweedRule,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Annotations of a single ayah, as they are in the dataset
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct TajweedAyah {
+    pub surah: i32,
+    pub ayah: i32,
+    pub annotations: Vec<TajweedAnnotation>,
+}
+
+/// A tajweed rule on a range of a single word
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordAnnotation {
+    /// Index of the word in the ayah, starting from 0
+    pub word: usize,
+    pub rule: TajweedRule,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Errors that can happen while reading the tajweed file
+#[derive(Debug, PartialEq)]
+pub enum TajweedError {
+    InvalidJson(String),
+    InvalidRange(AyahPosition, usize, usize),
+}
+
+impl Display for TajweedError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidJson(err) => write!(f, "file is not valid: {}", err),
+            Self::InvalidRange((surah, ayah), start, end) => write!(
+                f,
+                "range {}..{} is not in the text of ayah {}:{}",
+                start, end, surah, ayah
+            ),
+        }
+    }
+}
+
+impl From<TajweedError> for RouterError {
+    fn from(value: TajweedError) -> Self {
+        Self::from_predefined_with_detail("TAJWEED_NOT_VALID", &value.to_string())
+    }
+}
+
+/// What the tajweed import has created
+#[derive(Serialize, Debug)]
+pub struct TajweedReport {
+    pub ayahs: usize,
+    pub annotations: usize,
+}
+
+/// Parses the quran-tajweed json file
+pub fn parse_tajweed(content: &str) -> Result<Vec<TajweedAyah>, TajweedError> {
+    serde_json::from_str(content).map_err(|err| TajweedError::InvalidJson(err.to_string()))
+}
+
+/// Splits the ayah annotations into the words they cover,
+/// a range over several words is split into one range per word
+///
+/// The waqf sign of a word is a separate token in the dataset text,
+/// ranges on the sign itself are not kept
+pub fn split_annotations(
+    position: AyahPosition,
+    words: &[(&str, Option<WaqfMark>)],
+    annotations: &[TajweedAnnotation],
+) -> Result<Vec<WordAnnotation>, TajweedError> {
+    // Start and end of each word in the ayah text
+    let mut bounds = Vec::with_capacity(words.len());
+    let mut offset = 0;
+    for (word, mark) in words {
+        let length = word.chars().count();
+        bounds.push((offset, offset + length));
+        offset += length + 1;
+
+        // The sign and the space after it
+        if mark.is_some() {
+            offset += 2;
+        }
+    }
+
+    let text_length = offset.saturating_sub(1);
+    let mut result = vec![];
+
+    for annotation in annotations {
+        if annotation.start >= annotation.end || annotation.end > text_length {
+            return Err(TajweedError::InvalidRange(
+                position,
+                annotation.start,
+                annotation.end,
+            ));
+        }
+
+        for (index, (word_start, word_end)) in bounds.iter().enumerate() {
+            let start = annotation.start.max(*word_start);
+            let end = annotation.end.min(*word_end);
+
+            if start < end {
+                result.push(WordAnnotation {
+                    word: index,
+                    rule: annotation.rule,
+                    start: start - word_start,
+                    end: end - word_start,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Replaces the tajweed annotations of the mushaf words
+///
+/// Ayahs are found by their position, every ayah of the file must
+/// be in the mushaf
+pub fn import_tajweed(
+    conn: &mut PgConnection,
+    creator_user_id: i32,
+    mushaf: i32,
+    ayahs: Vec<TajweedAyah>,
+) -> Result<TajweedReport, RouterError> {
+    use crate::schema::quran_ayahs::dsl::{ayah_number, quran_ayahs};
+    use crate::schema::quran_surahs::dsl::{mushaf_id as surah_mushaf_id, number, quran_surahs};
+    use crate::schema::quran_word_tajweeds::dsl::{
+        quran_word_tajweeds, word_id as tajweed_word_id,
+    };
+    use crate::schema::quran_words::dsl::{id as word_id, quran_words, waqf, word, word_number};
+
+    let rows: Vec<(AyahPosition, TajweedWord)> = quran_words
+        .inner_join(quran_ayahs.inner_join(quran_surahs))
+        .filter(surah_mushaf_id.eq(mushaf))
+        .order(word_number.asc())
+        .select(((number, ayah_number), (word_id, word, waqf)))
+        .load(conn)?;
+
+    let mut mushaf_words: HashMap<AyahPosition, Vec<TajweedWord>> = HashMap::new();
+    for (position, ayah_word) in rows {
+        mushaf_words.entry(position).or_default().push(ayah_word);
+    }
+
+    let mut new_tajweeds: Vec<NewQuranWordTajweed> = vec![];
+
+    for ayah in &ayahs {
+        let position = (ayah.surah, ayah.ayah);
+
+        let Some(words) = mushaf_words.get(&position) else {
+            return Err(RouterError::from_predefined_with_detail(
+                "TAJWEED_NOT_VALID",
+                &format!("ayah {}:{} is not in the mushaf", ayah.surah, ayah.ayah),
+            ));
+        };
+
+        let texts: Vec<(&str, Option<WaqfMark>)> = words
+            .iter()
+            .map(|(_, text, mark)| (text.as_str(), WaqfMark::from_option_string(mark.clone())))
+            .collect();
+
+        for annotation in split_annotations(position, &texts, &ayah.annotations)? {
+            new_tajweeds.push(NewQuranWordTajweed {
+                creator_user_id,
+                word_id: words[annotation.word].0,
+                rule: annotation.rule.name(),
+                start_index: annotation.start as i32,
+                end_index: annotation.end as i32,
+            });
+        }
+    }
+
+    conn.transaction(|conn| {
+        let mushaf_word_ids = quran_words
+            .inner_join(quran_ayahs.inner_join(quran_surahs))
+            .filter(surah_mushaf_id.eq(mushaf))
+            .select(word_id);
+
+        diesel::delete(quran_word_tajweeds.filter(tajweed_word_id.eq_any(mushaf_word_ids)))
+            .execute(conn)?;
+
+        for chunk in new_tajweeds.chunks(MUSHAF_INSERT_CHUNK) {
+            chunk.insert_into(quran_word_tajweeds).execute(conn)?;
+        }
+
+        Ok(TajweedReport {
+            ayahs: ayahs.len(),
+            annotations: new_tajweeds.len(),
+        })
+    })
+}
+
+/// Runs the tajweed import from the command line
+///
+/// `nq-api tajweed --file tajweed.hafs.json --mushaf hafs --account admin`
+pub fn run_cli(pool: &DbPool, args: &[String]) -> io::Result<()> {
+    use crate::schema::quran_mushafs::dsl::{id as mushaf_id, quran_mushafs, short_name};
+
+    let options = CliOptions::parse(args)?;
+
+    let content = std::fs::read_to_string(options.required("file")?)?;
+
+    let ayahs = parse_tajweed(&content)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+
+    let mut conn = pool.get().unwrap();
+
+    let user = options.user(&mut conn)?;
+
+    let mushaf: i32 = quran_mushafs
+        .filter(short_name.eq(options.required("mushaf")?))
+        .select(mushaf_id)
+        .get_result(&mut conn)
+        .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err.to_string()))?;
+
+    let report = import_tajweed(&mut conn, user, mushaf, ayahs)
+        .map_err(|err| io::Error::other(err.to_string()))?;
+
+    println!("{}", serde_json::to_string_pretty(&report)?);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tajweed() {
+        let ayahs = parse_tajweed(
+            r#"[{"surah": 1, "ayah": 1, "annotations": [{"rule": "madd_2", "start": 3, "end": 5}]}]"#,
+        )
+        .unwrap();
+
+        assert_eq!(ayahs[0].surah, 1);
+        assert_eq!(ayahs[0].annotations[0].rule, TajweedRule::Madd2);
+        assert_eq!(TajweedRule::IdghaamNoGhunnah.name(), "idghaam_no_ghunnah");
+
+        assert!(
+            parse_tajweed(r#"[{"surah": 1, "ayah": 1, "annotations": [{"rule": "x"}]}]"#).is_err()
+        );
+    }
+
+    #[test]
+    fn test_split_annotations() {
+        let words = [("abc", None), ("de", None), ("fgh", None)];
+        let annotation = |start, end| TajweedAnnotation {
+            rule: TajweedRule::Ghunnah,
+            start,
+            end,
+        };
+
+        assert_eq!(
+            split_annotations((1, 1), &words, &[annotation(4, 6)]).unwrap(),
+            vec![WordAnnotation {
+                word: 1,
+                rule: TajweedRule::Ghunnah,
+                start: 0,
+                end: 2,
+            }]
+        );
+
+        // Over the space between two words
+        assert_eq!(
+            split_annotations((1, 1), &words, &[annotation(2, 5)]).unwrap(),
+            vec![
+                WordAnnotation {
+                    word: 0,
+                    rule: TajweedRule::Ghunnah,
+                    start: 2,
+                    end: 3,
+                },
+                WordAnnotation {
+                    word: 1,
+                    rule: TajweedRule::Ghunnah,
+                    start: 0,
+                    end: 1,
+                },
+            ]
+        );
+
+        assert_eq!(
+            split_annotations((1, 1), &words, &[annotation(8, 11)]),
+            Err(TajweedError::InvalidRange((1, 1), 8, 11))
+        );
+        assert!(split_annotations((1, 1), &words, &[annotation(3, 3)]).is_err());
+    }
+
+    #[test]
+    fn test_split_annotations_waqf() {
+        // Text of the dataset is "abc ۛ de fgh"
+        let words = [
+            ("abc", Some(WaqfMark::Muanaqah)),
+            ("de", None),
+            ("fgh", None),
+        ];
+        let annotation = |start, end| TajweedAnnotation {
+            rule: TajweedRule::Madd2,
+            start,
+            end,
+        };
+
+        assert_eq!(
+            split_annotations((2, 2), &words, &[annotation(6, 8), annotation(9, 12)]).unwrap(),
+            vec![
+                WordAnnotation {
+                    word: 1,
+                    rule: TajweedRule::Madd2,
+                    start: 0,
+                    end: 2,
+                },
+                WordAnnotation {
+                    word: 2,
+                    rule: TajweedRule::Madd2,
+                    start: 0,
+                    end: 3,
+                },
+            ]
+        );
+
+        // Only on the sign
+        assert!(split_annotations((2, 2), &words, &[annotation(4, 5)])
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,219 @@
+use serde::Deserialize;
+
+use crate::buckwalter;
+use crate::routers::quran::word::split_waqf;
+
+/// Latin transliteration of the quran text
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Transliteration {
+    /// Buckwalter with the quranic marks, can be converted back
+    Buckwalter,
+
+    /// Readable ALA-LC like romanization
+    Roman,
+}
+
+impl Transliteration {
+    /// Transliterates the words of the text, waqf signs are not written
+    pub fn transliterate(&self, text: &str) -> String {
+        text.split_whitespace()
+            .map(|word| split_waqf(word).0)
+            .filter(|word| !word.is_empty())
+            .map(|word| match self {
+                Self::Buckwalter => buckwalter::to_buckwalter(&word),
+                Self::Roman => romanize_word(&word),
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+const FATHA: char = '\u{064E}';
+const DAMMA: char = '\u{064F}';
+const KASRA: char = '\u{0650}';
+const SUKUN: char = '\u{0652}';
+const SHADDA: char = '\u{0651}';
+
+/// Quranic sukun (small high dotless head of khah)
+const SMALL_SUKUN: char = '\u{06E1}';
+
+fn consonant(c: char) -> Option<&'static str> {
+    Some(match c {
+        '\u{0621}' | '\u{0623}' | '\u{0624}' | '\u{0625}' | '\u{0626}' | '\u{0654}' => "ʾ",
+        'ب' => "b",
+        'ت' => "t",
+        'ث' => "th",
+        'ج' => "j",
+        'ح' => "ḥ",
+        'خ' => "kh",
+        'د' => "d",
+        'ذ' => "dh",
+        'ر' => "r",
+        'ز' => "z",
+        'س' => "s",
+        'ش' => "sh",
+        'ص' => "ṣ",
+        'ض' => "ḍ",
+        'ط' => "ṭ",
+        'ظ' => "ẓ",
+        'ع' => "ʿ",
+        'غ' => "gh",
+        'ف' => "f",
+        'ق' => "q",
+        'ك' => "k",
+        'ل' => "l",
+        'م' => "m",
+        'ن' => "n",
+        'ه' => "h",
+        'و' => "w",
+        'ي' => "y",
+
+        _ => return None,
+    })
+}
+
+/// Harakat, tanwin, sukun and shadda
+fn is_haraka(c: Option<&char>) -> bool {
+    matches!(c, Some('\u{064B}'..='\u{0652}') | Some(&SMALL_SUKUN))
+}
+
+/// Replaces the short vowel at the end with its long form
+fn lengthen(result: &mut String, short: char, long: char) -> bool {
+    if result.ends_with(short) {
+        result.pop();
+        result.push(long);
+
+        true
+    } else {
+        false
+    }
+}
+
+/// Romanizes a single word
+///
+/// The definite article is written as `al-`, the hamza at the start
+/// of the word and the quranic marks are not written. Words without
+/// harakat only get the long alif
+fn romanize_word(word: &str) -> String {
+    let mut chars: Vec<char> = word.chars().collect();
+
+    // The shadda can be before or after the vowel, keep it right after the letter
+    for index in 1..chars.len() {
+        if chars[index] == SHADDA && is_haraka(Some(&chars[index - 1])) {
+            chars.swap(index - 1, index);
+        }
+    }
+
+    let vocalized = chars.iter().any(|c| is_haraka(Some(c)));
+
+    let mut result = String::new();
+    let mut last_consonant = "";
+    let mut index = 0;
+
+    // The article, the lam has a sukun or the next letter is doubled
+    if chars.len() > 2 && matches!(chars[0], 'ا' | 'ٱ') && chars[1] == 'ل' {
+        let lam_sukun = matches!(chars[2], SUKUN | SMALL_SUKUN);
+        let sun_letter = chars.get(3) == Some(&SHADDA);
+
+        if !vocalized && chars.len() > 3 {
+            result.push_str("al-");
+            index = 2;
+        } else if lam_sukun || sun_letter {
+            result.push_str("al-");
+            index = if lam_sukun { 3 } else { 2 };
+
+            // The sun letter is not written twice
+            if sun_letter {
+                if let Some(letter) = consonant(chars[2]) {
+                    result.push_str(letter);
+                }
+                index = 4;
+            }
+        }
+    }
+
+    while index < chars.len() {
+        let c = chars[index];
+        let next = chars.get(index + 1);
+
+        match c {
+            FATHA => result.push('a'),
+            DAMMA => result.push('u'),
+            KASRA => result.push('i'),
+            '\u{064B}' | '\u{08F0}' => result.push_str("an"),
+            '\u{064C}' | '\u{08F1}' => result.push_str("un"),
+            '\u{064D}' | '\u{08F2}' => result.push_str("in"),
+            SHADDA => result.push_str(last_consonant),
+
+            // Dagger alif
+            '\u{0670}' => {
+                if !lengthen(&mut result, 'a', 'ā') {
+                    result.push('ā');
+                }
+            }
+
+            // Alif and alif maqsura are long after a fatha, silent otherwise
+            'ا' | 'ى' => {
+                if !vocalized && !result.is_empty() && !result.ends_with('-') {
+                    result.push('ā');
+                } else {
+                    lengthen(&mut result, 'a', 'ā');
+                }
+            }
+
+            'آ' => {
+                if !result.is_empty() {
+                    result.push('ʾ');
+                }
+                result.push('ā');
+            }
+
+            'و' | '\u{06E5}' if !is_haraka(next) && lengthen(&mut result, 'u', 'ū') => {}
+            'ي' | '\u{06E6}' if !is_haraka(next) && lengthen(&mut result, 'i', 'ī') => {}
+
+            // Ta marbuta is read only when it has a vowel
+            'ة' => result.push(if is_haraka(next) { 't' } else { 'h' }),
+
+            _ => {
+                if let Some(letter) = consonant(c) {
+                    // Hamza is not written at the start of the word
+                    if !(letter == "ʾ" && result.is_empty()) {
+                        result.push_str(letter);
+                    }
+                    last_consonant = letter;
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_romanize() {
+        let roman = |text| Transliteration::Roman.transliterate(text);
+
+        assert_eq!(roman("بِسْمِ"), "bismi");
+        assert_eq!(roman("ٱلْحَمْدُ لِلَّهِ"), "al-ḥamdu lillahi");
+        assert_eq!(roman("ٱلرَّحْمَٰنِ ٱلرَّحِيمِ"), "al-raḥmāni al-raḥīmi");
+        assert_eq!(roman("قُولُوٓا۟"), "qūlū");
+        assert_eq!(roman("هُدًى"), "hudan");
+        assert_eq!(roman("أَنزَلْنَٰهُ"), "anzalnāhu");
+        assert_eq!(roman("الكتاب"), "al-ktāb");
+    }
+
+    #[test]
+    fn test_buckwalter() {
+        assert_eq!(
+            Transliteration::Buckwalter.transliterate("ذَٰلِكَ ٱلْكِتَٰبُ ۛ"),
+            "*a`lika {lokita`bu"
+        );
+    }
+}